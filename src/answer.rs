@@ -0,0 +1,137 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+
+/// Answer of a single part, see [`IntoAnswer`] for what solvers can return.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// An integer of any width.
+    Int(i128),
+    /// An unsigned integer that does not fit into an `i128`.
+    BigInt(u128),
+    /// A single line of text, e.g. the top crates of day 05.
+    Text(String),
+    /// Multi-line text, e.g. the rendered CRT picture of day 10.
+    Grid(String),
+}
+
+impl Answer {
+    /// Compares the answer to an accepted answer as stored in `src/yYYYY/answers/`.
+    /// Integers are compared numerically. Text ignores surrounding whitespace and grids
+    /// ignore trailing whitespace on each line as well as leading and trailing blank lines.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Int(answer) => expected.trim().parse::<i128>() == Ok(*answer),
+            Answer::BigInt(answer) => expected.trim().parse::<u128>() == Ok(*answer),
+            Answer::Text(answer) => answer.trim() == expected.trim(),
+            Answer::Grid(answer) => grid_lines(answer).eq(grid_lines(expected)),
+        }
+    }
+}
+
+fn grid_lines(grid: &str) -> impl Iterator<Item = &str> {
+    grid.trim_matches('\n').lines().map(str::trim_end)
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(answer) => write!(f, "{}", answer),
+            Answer::BigInt(answer) => write!(f, "{}", answer),
+            Answer::Text(answer) | Answer::Grid(answer) => write!(f, "{}", answer),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(answer: $int) -> Self {
+                    Answer::Int(answer as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<u128> for Answer {
+    fn from(answer: u128) -> Self {
+        i128::try_from(answer).map_or(Answer::BigInt(answer), Answer::Int)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        if answer.trim_end().contains('\n') {
+            Answer::Grid(answer)
+        } else {
+            Answer::Text(answer)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer::from(answer.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(answer: char) -> Self {
+        Answer::Text(answer.to_string())
+    }
+}
+
+/// Return type of a solver: an `Option` of anything that converts into an [`Answer`], e.g.
+/// `Option<u32>`, `Option<u64>` or `Option<String>`. `None` means the part is not solved yet.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Option<Answer>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Option<Answer> {
+        self.map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(24000_u32), Answer::Int(24000));
+        assert_eq!(Answer::from(2713310158_u64), Answer::Int(2713310158));
+        assert_eq!(Answer::from(-3_isize), Answer::Int(-3));
+        assert_eq!(Answer::from(42_u128), Answer::Int(42));
+        assert_eq!(Answer::from(u128::MAX), Answer::BigInt(u128::MAX));
+        assert_eq!(Some(7_u8).into_answer(), Some(Answer::Int(7)));
+        assert_eq!(None::<String>.into_answer(), None);
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".into()));
+        assert_eq!(
+            Answer::from("##..\n#...\n"),
+            Answer::Grid("##..\n#...\n".into())
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(Answer::Int(24000).matches("24000"));
+        assert!(Answer::Int(24000).matches(" 24000\n"));
+        assert!(!Answer::Int(24000).matches("24001"));
+        assert!(!Answer::Int(24000).matches("CMZ"));
+        assert!(Answer::BigInt(u128::MAX).matches(&u128::MAX.to_string()));
+        assert!(!Answer::BigInt(u128::MAX).matches("-1"));
+
+        assert!(Answer::Text("CMZ".into()).matches("CMZ\n"));
+        assert!(!Answer::Text("CMZ".into()).matches("MCD"));
+
+        assert!(Answer::Grid("##..\n#...\n".into()).matches("\n##..\n#...  \n"));
+        assert!(!Answer::Grid("##..\n#...\n".into()).matches("##..\n#..#\n"));
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use toml::Value;
+
+/// Accepted answers of a day, stored in `src/yYYYY/answers/NN.toml`:
+///
+/// ```toml
+/// part_one = 24000
+/// part_two = "45000"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let table = match contents.parse::<Value>().map_err(|e| e.to_string())? {
+            Value::Table(table) => table,
+            _ => return Err("expected a table".into()),
+        };
+
+        let answer = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(Value::String(answer)) => Ok(Some(answer.clone())),
+            Some(Value::Integer(answer)) => Ok(Some(answer.to_string())),
+            Some(other) => Err(format!(
+                "`{}` must be a string or an integer, found {}",
+                key,
+                other.type_str()
+            )),
+        };
+
+        Ok(Answers {
+            part_one: answer("part_one")?,
+            part_two: answer("part_two")?,
+        })
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut table = toml::value::Table::new();
+        for (key, answer) in [("part_one", &self.part_one), ("part_two", &self.part_two)] {
+            if let Some(answer) = answer {
+                let value = match answer.parse::<i64>() {
+                    Ok(number) => Value::Integer(number),
+                    Err(_) => Value::String(answer.clone()),
+                };
+                table.insert(key.into(), value);
+            }
+        }
+        toml::to_string_pretty(&table).unwrap()
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_one = Some(answer),
+            2 => self.part_two = Some(answer),
+            _ => {}
+        }
+    }
+}
+
+pub fn answers_path(year: u16, day: u8) -> PathBuf {
+    crate::year_dir(year)
+        .join("answers")
+        .join(format!("{:02}.toml", day))
+}
+
+/// Reads the recorded answers of `day`. A missing file means no answers were recorded yet.
+pub fn read_answers(year: u16, day: u8) -> Result<Answers, String> {
+    read_answers_file(&answers_path(year, day))
+}
+
+/// Reads answers from `path`, e.g. the expected answers of a named input.
+/// A missing file means no answers were recorded yet.
+pub fn read_answers_file(path: &Path) -> Result<Answers, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Answers::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+/// Records `answer` for the given part, unless an answer was already recorded.
+/// Returns `true` if the store was updated.
+pub fn record_answer(year: u16, day: u8, part: u8, answer: &str) -> Result<bool, String> {
+    let mut answers = read_answers(year, day)?;
+    if answers.get(part).is_some() {
+        return Ok(false);
+    }
+    answers.set(part, answer.to_string());
+
+    let path = answers_path(year, day);
+    fs::write(&path, answers.to_toml()).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("part_one = 24000\npart_two = \"45000\"\n").unwrap();
+        assert_eq!(answers.get(1), Some("24000"));
+        assert_eq!(answers.get(2), Some("45000"));
+
+        let answers = Answers::parse("part_two = '''\n##..\n#...\n'''\n").unwrap();
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("##..\n#...\n"));
+
+        assert!(Answers::parse("part_one = 1.5").is_err());
+        assert!(Answers::parse("part_one = ").is_err());
+    }
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let answers = Answers {
+            part_one: Some("2713310158".into()),
+            part_two: Some("##..\n#...\n".into()),
+        };
+        assert_eq!(Answers::parse(&answers.to_toml()).unwrap(), answers);
+
+        let answers = Answers {
+            part_one: None,
+            part_two: Some("CMZ".into()),
+        };
+        assert_eq!(answers.to_toml(), "part_two = 'CMZ'\n");
+    }
+}
@@ -0,0 +1,188 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Minimum time spent running a part before samples are taken.
+const WARMUP_TIME: Duration = Duration::from_millis(100);
+/// Time budget for taking samples. Slow parts still get `MIN_SAMPLES` samples.
+const MEASURE_TIME: Duration = Duration::from_secs(1);
+const MIN_SAMPLES: usize = 10;
+const MAX_SAMPLES: usize = 10_000;
+
+/// Timing statistics of repeated runs of a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        BenchStats {
+            samples: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Runs `solver` repeatedly: first for `WARMUP_TIME`, then for a number of samples that fits
+/// into `MEASURE_TIME`. Returns the result of the last run along with timing statistics.
+pub fn bench<R>(solver: impl Fn(&str) -> R, input: &str) -> (R, BenchStats) {
+    let warmup = Instant::now();
+    let mut warmup_runs = 0;
+    while warmup_runs == 0 || warmup.elapsed() < WARMUP_TIME {
+        solver(input);
+        warmup_runs += 1;
+    }
+
+    let estimate = warmup.elapsed() / warmup_runs;
+    let n = (MEASURE_TIME.as_nanos() / estimate.as_nanos().max(1))
+        .clamp(MIN_SAMPLES as u128, MAX_SAMPLES as u128) as usize;
+
+    let mut samples = Vec::with_capacity(n);
+    let mut result = None;
+    for _ in 0..n {
+        let timer = Instant::now();
+        result = Some(solver(input));
+        samples.push(timer.elapsed());
+    }
+
+    // there are always at least `MIN_SAMPLES` samples.
+    let result = result.expect("no samples were taken");
+    (result, BenchStats::from_samples(&mut samples))
+}
+
+/// Median timings of previous bench runs, keyed by `(year, day, part)`.
+pub type Baseline = BTreeMap<(u16, u8, u8), Duration>;
+
+/// Parses a baseline file. Each line holds `<year> <day> <part> <median in ns>`, `#` starts a
+/// comment. Lines in any other shape are skipped.
+pub fn parse_baseline(contents: &str) -> Baseline {
+    contents
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(|line| {
+            let [year, day, part, median] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+                return None;
+            };
+            let key = (year.parse().ok()?, day.parse().ok()?, part.parse().ok()?);
+            Some((key, Duration::from_nanos(median.parse().ok()?)))
+        })
+        .collect()
+}
+
+pub fn format_baseline(baseline: &Baseline) -> String {
+    let mut out = String::from("# year day part median_ns\n");
+    for ((year, day, part), median) in baseline {
+        out.push_str(&format!(
+            "{} {:02} {} {}\n",
+            year,
+            day,
+            part,
+            median.as_nanos()
+        ));
+    }
+    out
+}
+
+/// Reads a baseline file. A missing file is treated as an empty baseline.
+pub fn read_baseline(path: &Path) -> io::Result<Baseline> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(parse_baseline(&contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Baseline::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Stores `median` as the baseline of the given part, keeping entries of other parts intact.
+pub fn update_baseline(
+    path: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    median: Duration,
+) -> io::Result<()> {
+    let mut baseline = read_baseline(path)?;
+    baseline.insert((year, day, part), median);
+    fs::write(path, format_baseline(&baseline))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let stats = BenchStats::from_samples(&mut samples);
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        assert_eq!(stats.stddev.as_nanos(), 1118);
+
+        let mut samples = [Duration::from_millis(3)];
+        let stats = BenchStats::from_samples(&mut samples);
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let mut baseline = Baseline::new();
+        baseline.insert((2022, 11, 2), Duration::from_nanos(11_458_677));
+        baseline.insert((2022, 1, 1), Duration::from_nanos(34_128));
+
+        let formatted = format_baseline(&baseline);
+        assert_eq!(
+            formatted,
+            "# year day part median_ns\n2022 01 1 34128\n2022 11 2 11458677\n"
+        );
+        assert_eq!(parse_baseline(&formatted), baseline);
+        // files from before years were recorded have no year column.
+        assert!(parse_baseline("01 1 34128\n").is_empty());
+    }
+
+    #[test]
+    fn test_baseline_years() {
+        let path = std::env::temp_dir().join("advent_of_code_baseline_years.txt");
+        let _ = fs::remove_file(&path);
+        update_baseline(&path, 2021, 5, 1, Duration::from_nanos(100)).unwrap();
+        update_baseline(&path, 2022, 5, 1, Duration::from_nanos(200)).unwrap();
+
+        let baseline = read_baseline(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline[&(2021, 5, 1)], Duration::from_nanos(100));
+        assert_eq!(baseline[&(2022, 5, 1)], Duration::from_nanos(200));
+    }
+}
@@ -0,0 +1,111 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client::{self, Client};
+use advent_of_code::puzzle;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process;
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        force: args.contains(["-f", "--force"]),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
+    })
+}
+
+/// Writes a file, creating its folder first, e.g. when the year was not scaffolded yet.
+fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(path, contents)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let year = args.year.unwrap_or_else(client::default_year);
+    let puzzle_path = advent_of_code::year_dir(year)
+        .join("puzzles")
+        .join(format!("{:02}.md", args.day));
+    let example_path = advent_of_code::input_path(year, "examples", args.day);
+
+    // the description only changes when part two unlocks.
+    let complete = fs::read_to_string(&puzzle_path)
+        .is_ok_and(|puzzle| puzzle.contains(puzzle::PART_TWO_HEADING));
+    if complete && !args.force {
+        println!(
+            "🎄 Puzzle already saved to \"{}\". Pass --force to download it again.",
+            puzzle_path.display()
+        );
+        process::exit(0);
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to set up client: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("Downloading puzzle for day {}, {}...", args.day, year);
+
+    let html = match client.puzzle(year, args.day) {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("Failed to download puzzle: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let markdown = puzzle::to_markdown(&html);
+    if markdown.trim().is_empty() {
+        eprintln!("Failed to find the puzzle description in the page.");
+        process::exit(1);
+    }
+
+    println!("---");
+    match write_file(&puzzle_path, &markdown) {
+        Ok(_) => println!("Wrote puzzle description to \"{}\"", puzzle_path.display()),
+        Err(e) => {
+            eprintln!("Failed to write puzzle file: {}", e);
+            process::exit(1);
+        }
+    }
+
+    // never overwrite an example that was already filled in.
+    let has_example = fs::metadata(&example_path).is_ok_and(|meta| meta.len() > 0);
+    match puzzle::first_example(&html) {
+        Some(example) if !has_example => match write_file(&example_path, &example) {
+            Ok(_) => println!("Wrote example to \"{}\"", example_path.display()),
+            Err(e) => eprintln!("Failed to write example file: {}", e),
+        },
+        Some(_) => {}
+        None => println!(
+            "No example found, fill in \"{}\" manually.",
+            example_path.display()
+        ),
+    }
+
+    if markdown.contains(puzzle::PART_TWO_HEADING) {
+        println!("🎄 Both parts saved.");
+    } else {
+        println!("🎄 Part one saved. Run this command again once part two unlocks.");
+    }
+}
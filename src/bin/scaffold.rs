@@ -2,47 +2,69 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::client;
+use advent_of_code::puzzle::{self, Example};
 use std::{
-    fs::{File, OpenOptions},
+    env,
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
+const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<PART_ONE_TYPE> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<PART_TWO_TYPE> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_one(&input), PART_ONE_EXPECTED);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_two(&input), PART_TWO_EXPECTED);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+const YEAR_TEMPLATE: &str = r###"/*
+ * Solutions of YEAR live in this folder, one module per day.
+ * `cargo scaffold` creates new modules and registers them below.
+ */
+days! {
+}
+"###;
+
+/// Registry of the years, see the `years!` invocation.
+const YEARS_REGISTRY_PATH: &str = "src/lib.rs";
+
+/// Folders holding the data files of a year.
+const DATA_FOLDERS: &[&str] = &["inputs", "examples", "answers", "puzzles"];
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+    puzzle: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        puzzle: args.opt_value_from_str(["-p", "--puzzle"])?,
+        day: args.free_from_str()?,
+    })
 }
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
@@ -50,65 +72,250 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Return type and expected test value of a part, derived from its example answer.
+/// The type is the smallest one the example answer fits in, any `Option` of a type that
+/// converts into an `Answer` works for the runner.
+fn answer_template(answer: Option<&str>) -> (&'static str, String) {
+    match answer {
+        None => ("u32", "None".to_string()),
+        Some(answer) if answer.parse::<u32>().is_ok() => ("u32", format!("Some({})", answer)),
+        Some(answer) if answer.parse::<u64>().is_ok() => ("u64", format!("Some({})", answer)),
+        Some(answer) if answer.parse::<i64>().is_ok() => ("i64", format!("Some({})", answer)),
+        Some(answer) if answer.parse::<u128>().is_ok() => ("u128", format!("Some({})", answer)),
+        Some(answer) if answer.parse::<i128>().is_ok() => ("i128", format!("Some({})", answer)),
+        Some(answer) => ("String", format!("Some({:?}.to_string())", answer)),
+    }
+}
+
+fn write_template(
+    path: &str,
+    template: &str,
+    year: u16,
+    day: u8,
+    example: Option<&Example>,
+) -> Result<(), std::io::Error> {
+    let (part_one_type, part_one_expected) =
+        answer_template(example.and_then(|e| e.part_one.as_deref()));
+    let (part_two_type, part_two_expected) =
+        answer_template(example.and_then(|e| e.part_two.as_deref()));
+
+    let contents = template
+        .replace("DAY", &day.to_string())
+        .replace("YEAR", &year.to_string())
+        .replace("PART_ONE_TYPE", part_one_type)
+        .replace("PART_TWO_TYPE", part_two_type)
+        .replace("PART_ONE_EXPECTED", &part_one_expected)
+        .replace("PART_TWO_EXPECTED", &part_two_expected);
+    safe_create_file(path)?.write_all(contents.as_bytes())
+}
+
+/// Reads the example from a saved puzzle page or Markdown description.
+fn read_example(path: &Path) -> Result<Example, String> {
+    let puzzle = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    puzzle::example(&puzzle).ok_or_else(|| format!("{}: could not find an example", path.display()))
+}
+
+/// Declares `pub mod module;` in front of the `name!` invocation of a registry and adds
+/// `key => module,` to it, keeping both sorted.
+fn register(registry: &str, name: &str, key: u16, module: &str) -> Result<String, String> {
+    let declaration = format!("pub mod {};", module);
+    let entry = format!("    {} => {},", key, module);
+    let mut lines: Vec<&str> = registry.lines().collect();
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(&format!("{}! {{", name)))
+        .ok_or(format!("could not find `{}!` invocation", name))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "}")
+            .ok_or(format!("could not find end of `{}!` invocation", name))?;
+
+    let mut insert_at = end;
+    for (i, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let registered: Option<u16> = line.split("=>").next().and_then(|k| k.trim().parse().ok());
+        match registered {
+            Some(registered) if registered == key => {
+                return Err(format!("{} is already registered", key))
+            }
+            Some(registered) if registered > key => {
+                insert_at = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    lines.insert(insert_at, &entry);
+
+    // the declarations are the `pub mod` lines right above the invocation.
+    let mut below = start;
+    while below > 0 && lines[below - 1].is_empty() {
+        below -= 1;
+    }
+    let mut first = below;
+    while first > 0 && lines[first - 1].starts_with("pub mod ") {
+        first -= 1;
+    }
+    if first == below {
+        lines.insert(start, "");
+        lines.insert(start, &declaration);
+    } else {
+        let insert_at = (first..below)
+            .find(|&i| lines[i] > declaration.as_str())
+            .unwrap_or(below);
+        lines.insert(insert_at, &declaration);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn register_in(path: &str, name: &str, key: u16, module: &str) -> Result<(), String> {
+    fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|registry| register(&registry, name, key, module))
+        .and_then(|registry| fs::write(path, registry).map_err(|e| e.to_string()))
+}
+
+/// Creates the folder, registry and data folders of a new year and registers it.
+fn create_year(year: u16, year_dir: &str, registry_path: &str) -> Result<(), String> {
+    for folder in DATA_FOLDERS {
+        let path = format!("{}/{}", year_dir, folder);
+        fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+        create_file(&format!("{}/.keep", path)).map_err(|e| e.to_string())?;
+    }
+    let registry = YEAR_TEMPLATE.replace("YEAR", &year.to_string());
+    fs::write(registry_path, registry).map_err(|e| e.to_string())?;
+    register_in(YEARS_REGISTRY_PATH, "years", year, &format!("y{}", year))
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
+    // paths below are relative to the project root.
+    if let Err(e) = env::set_current_dir(advent_of_code::root_dir()) {
+        eprintln!("Failed to find project root: {}", e);
+        process::exit(1);
+    }
+
+    let day = args.day;
     let day_padded = format!("{:02}", day);
+    let year = args.year.unwrap_or_else(client::default_year);
+    let year_dir = format!("src/y{}", year);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let input_path = format!("{}/inputs/{}.txt", year_dir, day_padded);
+    let example_path = format!("{}/examples/{}.txt", year_dir, day_padded);
+    let module_path = format!("{}/day{}.rs", year_dir, day_padded);
+    let registry_path = format!("{}/mod.rs", year_dir);
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+    if !Path::new(&registry_path).exists() {
+        match create_year(year, &year_dir, &registry_path) {
+            Ok(_) => {
+                println!(
+                    "Created \"{}\" and registered {} in \"{}\"",
+                    &year_dir, year, YEARS_REGISTRY_PATH
+                );
+            }
+            Err(e) => {
+                eprintln!("Failed to create year: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // use the description saved by `cargo read` unless a puzzle file is passed.
+    let saved_puzzle = PathBuf::from(format!("{}/puzzles/{}.md", year_dir, day_padded));
+    let puzzle_path = args
+        .puzzle
+        .or_else(|| saved_puzzle.exists().then_some(saved_puzzle));
+
+    let example = match puzzle_path.as_deref().map(read_example) {
+        Some(Ok(example)) => {
+            println!(
+                "Found example in \"{}\"",
+                puzzle_path.as_ref().unwrap().display()
+            );
+            Some(example)
+        }
+        Some(Err(e)) => {
+            eprintln!("Failed to read example: {}", e);
             process::exit(1);
         }
+        None => None,
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match write_template(&module_path, MODULE_TEMPLATE, year, day, example.as_ref()) {
         Ok(_) => {
             println!("Created module file \"{}\"", &module_path);
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+            eprintln!("Failed to create module file: {}", e);
             process::exit(1);
         }
     }
 
-    match create_file(&input_path) {
+    match register_in(
+        &registry_path,
+        "days",
+        day.into(),
+        &format!("day{}", day_padded),
+    ) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Registered day {} in \"{}\"", day, &registry_path);
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to register day: {}", e);
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
+    match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty input file \"{}\"", &input_path);
         }
         Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+            eprintln!("Failed to create input file: {}", e);
             process::exit(1);
         }
     }
 
+    let has_example = fs::metadata(&example_path).is_ok_and(|meta| meta.len() > 0);
+    match example {
+        Some(example) if !has_example => match fs::write(&example_path, example.input) {
+            Ok(_) => {
+                println!("Wrote example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to write example file: {}", e);
+                process::exit(1);
+            }
+        },
+        _ => match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {}", e);
+                process::exit(1);
+            }
+        },
+    }
+
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        &day_padded, year
     );
 }
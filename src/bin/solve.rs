@@ -0,0 +1,94 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::options::Options;
+use advent_of_code::profile;
+use std::env;
+use std::process::{self, Command};
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+
+    // cargo passes everything after the day to this binary, cargo flags only work before it.
+    if args.contains("--release") {
+        eprintln!("`--release` is a cargo flag, pass it before the day: `cargo solve --release 7`");
+        process::exit(1);
+    }
+
+    // rebuild with allocation counting if asked to, in the same profile as this build.
+    if args.contains("--memory") && !advent_of_code::memory::enabled() {
+        let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+        let mut command = Command::new(cargo);
+        command.args(["run", "--quiet", "--features", "memory"]);
+        if !cfg!(debug_assertions) {
+            command.arg("--release");
+        }
+        let status = command
+            .args(["--bin", "solve", "--"])
+            .args(env::args_os().skip(1).filter(|arg| arg != "--memory"))
+            .status();
+        match status {
+            Ok(status) => process::exit(status.code().unwrap_or(1)),
+            Err(e) => {
+                eprintln!("Failed to run cargo: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // skip the runner flags, they are read by `Options::current`.
+    if let Err(e) = Options::parse(&mut args) {
+        eprintln!("Failed to process arguments: {}", e);
+        process::exit(1);
+    }
+
+    let day: u8 = match args.free_from_str() {
+        Ok(day) => day,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo solve 7`");
+            process::exit(1);
+        }
+    };
+
+    let year = Options::current().year();
+    let solution = match advent_of_code::find_day(year, day) {
+        Some(solution) => solution,
+        None => {
+            eprintln!(
+                "Day {} of {} is not registered. Type `cargo scaffold {} --year {}` to create it.",
+                day, year, day, year
+            );
+            process::exit(1);
+        }
+    };
+
+    let options = Options::current();
+    let input = &match advent_of_code::read_selected_input(year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {}.", e);
+            if options.puzzle_input() {
+                eprintln!(
+                    "Type `cargo download {} --year {}` to download it.",
+                    day, year
+                );
+            } else if options.example {
+                eprintln!(
+                    "Paste the example into it or type `cargo read {} --year {}`.",
+                    day, year
+                );
+            }
+            process::exit(1);
+        }
+    };
+
+    if let Some(runs) = options.profile {
+        for (part, profile) in profile::profile_day(solution, input, runs) {
+            println!("Part {}: {}", part, profile);
+        }
+        return;
+    }
+
+    advent_of_code::run_day(year, solution, input);
+}
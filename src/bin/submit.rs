@@ -0,0 +1,119 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client::{self, Client};
+use advent_of_code::helpers::ocr;
+use advent_of_code::submit::{self, Verdict};
+use advent_of_code::{answers, Answer};
+use std::process;
+use std::time::Instant;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+    })
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with(&format!("Failed to process arguments: {}", e)),
+    };
+
+    let year = args.year.unwrap_or_else(client::default_year);
+    let solution = advent_of_code::find_day(year, args.day)
+        .unwrap_or_else(|| exit_with(&format!("Day {} of {} is not registered.", args.day, year)));
+    let solver = match args.part {
+        1 => solution.part_one,
+        2 => solution.part_two,
+        _ => exit_with("Part must be 1 or 2."),
+    };
+
+    let input = advent_of_code::try_read_file(year, "inputs", args.day).unwrap_or_else(|e| {
+        exit_with(&format!(
+            "Failed to read input: {}.\nType `cargo download {} --year {}` to download it.",
+            e, args.day, year
+        ))
+    });
+
+    let timer = Instant::now();
+    let answer = match solver(&input) {
+        Some(answer) => answer,
+        None => exit_with("Part is not solved yet, nothing to submit."),
+    };
+    println!("{} (elapsed: {:.2?})", answer, timer.elapsed());
+
+    // rendered letters have to be submitted as text.
+    let answer = match answer {
+        Answer::Grid(grid) => ocr(&grid).unwrap_or_else(|| {
+            exit_with("Could not read letters from the answer, submit it manually.")
+        }),
+        answer => answer.to_string(),
+    };
+
+    let mut guesses = submit::read_guesses(year, args.day).unwrap_or_else(|e| exit_with(&e));
+    if let Err(reason) = guesses.check(args.part, &answer, submit::now()) {
+        exit_with(&format!("Not submitting \"{}\": {}", answer, reason));
+    }
+
+    let client = Client::from_env()
+        .unwrap_or_else(|e| exit_with(&format!("Failed to set up client: {}", e)));
+    println!(
+        "Submitting \"{}\" for day {} part {}, {}...",
+        answer, args.day, args.part, year
+    );
+
+    let (verdict, wait) = submit::submit(&client, year, args.day, args.part, &answer)
+        .unwrap_or_else(|e| exit_with(&format!("Failed to submit answer: {}", e)));
+
+    guesses.record(args.part, &answer, verdict, wait, submit::now());
+    if let Err(e) = submit::write_guesses(year, args.day, &guesses) {
+        eprintln!("Failed to record guess: {}", e);
+    }
+
+    println!("---");
+    match verdict {
+        Verdict::Correct => {
+            println!("⭐ That's the right answer!");
+            match answers::record_answer(year, args.day, args.part, &answer) {
+                Ok(true) => println!(
+                    "Recorded answer in \"{}\".",
+                    answers::answers_path(year, args.day).display()
+                ),
+                Ok(false) => {}
+                Err(e) => eprintln!("Failed to record answer: {}", e),
+            }
+        }
+        Verdict::Wrong => println!("❌ That's not the right answer."),
+        Verdict::TooHigh => println!("❌ That's not the right answer, it is too high."),
+        Verdict::TooLow => println!("❌ That's not the right answer, it is too low."),
+        Verdict::Wait(wait) => println!(
+            "⏳ You gave an answer too recently, wait {}s before submitting again.",
+            wait.as_secs()
+        ),
+        Verdict::WrongLevel => println!("This part is locked or was already solved."),
+    }
+
+    if let (Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow, Some(wait)) = (verdict, wait) {
+        println!("You can submit again in {}s.", wait.as_secs());
+    }
+
+    if verdict != Verdict::Correct {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,77 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::options::Options;
+use advent_of_code::readme::{self, Timing};
+use advent_of_code::{runner, Status, ANSI_BOLD, ANSI_RESET};
+use std::process;
+
+fn main() {
+    let options = Options::current();
+    if !options.puzzle_input() {
+        eprintln!(
+            "`cargo time` measures the puzzle inputs, --input and --example are not supported."
+        );
+        process::exit(1);
+    }
+
+    let year = options.year();
+    let mut timings = Vec::new();
+    let mut failed = 0;
+
+    for day in (1..=25).filter(|day| options.runs_day(*day)) {
+        let Some(solution) = advent_of_code::find_day(year, day) else {
+            continue;
+        };
+        let input = match advent_of_code::read_selected_input(year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping day {}: {}.", day, e);
+                continue;
+            }
+        };
+
+        println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+        let mut timing = Timing {
+            day,
+            part_one: None,
+            part_two: None,
+        };
+        for result in runner::run_day(year, solution, &input) {
+            let elapsed = match result.status() {
+                Status::Solved | Status::Correct => Some(result.elapsed),
+                Status::Unsolved => None,
+                Status::Wrong | Status::Panicked | Status::TimedOut => {
+                    failed += 1;
+                    None
+                }
+            };
+            match result.part {
+                1 => timing.part_one = elapsed,
+                _ => timing.part_two = elapsed,
+            }
+        }
+        if timing.part_one.is_some() || timing.part_two.is_some() {
+            timings.push(timing);
+        }
+    }
+
+    println!("---");
+    if failed > 0 {
+        eprintln!("Not updating the README, {} parts failed.", failed);
+        process::exit(1);
+    }
+
+    match readme::update_readme(year, &timings) {
+        Ok(_) => println!(
+            "🎄 Updated timings of {} days in \"{}\".",
+            timings.len(),
+            readme::readme_path().display()
+        ),
+        Err(e) => {
+            eprintln!("Failed to update README: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::options::Options;
+use advent_of_code::runner;
+use advent_of_code::{PartResult, Status, ANSI_BOLD, ANSI_RESET};
+use std::path::Path;
+use std::process;
+use std::sync::Arc;
+use std::time::Instant;
+
+fn main() {
+    // skip the runner flags, they are read by `Options::current`.
+    let mut args = pico_args::Arguments::from_env();
+    if let Err(e) = Options::parse(&mut args) {
+        eprintln!("Failed to process arguments: {}", e);
+        process::exit(1);
+    }
+
+    let day: u8 = match args.free_from_str() {
+        Ok(day) => day,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo validate 7`");
+            process::exit(1);
+        }
+    };
+
+    let year = Options::current().year();
+    let solution = match advent_of_code::find_day(year, day) {
+        Some(solution) => solution,
+        None => {
+            eprintln!("Day {} of {} is not registered.", day, year);
+            process::exit(1);
+        }
+    };
+
+    let files = advent_of_code::input_files(year, day);
+    if files.is_empty() {
+        eprintln!("No inputs or examples found for day {} of {}.", day, year);
+        process::exit(1);
+    }
+
+    let mut failed = 0;
+    for file in &files {
+        let file_name = file.path.file_name().unwrap_or_default();
+        println!(
+            "{}{}{}",
+            ANSI_BOLD,
+            Path::new(&file.folder).join(file_name).display(),
+            ANSI_RESET
+        );
+
+        // a broken file fails on its own, the other files are still run.
+        let input: Arc<str> = match advent_of_code::read_input(&file.path) {
+            Ok(input) => input.into(),
+            Err(e) => {
+                eprintln!("Failed to read input: {}", e);
+                println!();
+                failed += 1;
+                continue;
+            }
+        };
+        let expected = file.expected(year, day).unwrap_or_else(|e| {
+            eprintln!("Failed to read answers: {}", e);
+            Default::default()
+        });
+
+        for (part, solver) in solution.selected_parts() {
+            let (input, expected) = (input.clone(), expected.get(part).map(str::to_string));
+            let result = runner::run_isolated(year, day, part, move || {
+                let timer = Instant::now();
+                let answer = solver(&input);
+                PartResult {
+                    year,
+                    day,
+                    part,
+                    answer,
+                    expected,
+                    elapsed: timer.elapsed(),
+                    bench: None,
+                    baseline: None,
+                    failure: None,
+                    memory: None,
+                }
+            });
+
+            print!("Part {}: ", part);
+            result.print();
+            if matches!(
+                result.status(),
+                Status::Wrong | Status::Panicked | Status::TimedOut
+            ) {
+                failed += 1;
+            }
+        }
+        println!();
+    }
+
+    println!("---");
+    println!("🎄 Ran {} inputs, {} failures.", files.len(), failed);
+    if failed > 0 {
+        process::exit(1);
+    }
+}
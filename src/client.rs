@@ -0,0 +1,350 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides the server the client talks to, e.g. to point it at a local stand-in.
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+/// Session cookie. Takes precedence over the session file.
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+/// Year used when no `--year` is passed.
+pub const YEAR_ENV_VAR: &str = "AOC_YEAR";
+
+/// Session file in the home directory, containing the session cookie.
+const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Identifies this tool to the server, as requested by the Advent of Code maintainers.
+/// Points to the `repository` of `Cargo.toml`, so that they can reach whoever runs this copy.
+fn user_agent() -> String {
+    match env!("CARGO_PKG_REPOSITORY") {
+        "" => format!("advent_of_code/{}", env!("CARGO_PKG_VERSION")),
+        repository => format!(
+            "advent_of_code/{} (+{})",
+            env!("CARGO_PKG_VERSION"),
+            repository
+        ),
+    }
+}
+
+/// Minimum time between two requests to the server, shared by all commands.
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum Error {
+    /// No session cookie in `AOC_SESSION` or `~/.adventofcode.session`.
+    MissingSession,
+    /// The puzzle is not unlocked yet or the day does not exist.
+    NotFound,
+    /// The server rejected the session cookie.
+    Unauthorized,
+    Status(u16, String),
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session cookie found. Set the {} env var or create \"~/{}\".",
+                SESSION_ENV_VAR, SESSION_FILE
+            ),
+            Error::NotFound => write!(f, "puzzle not found. Is it unlocked yet?"),
+            Error::Unauthorized => write!(
+                f,
+                "the session cookie was rejected. It might have expired, try refreshing it."
+            ),
+            Error::Status(status, body) => {
+                write!(
+                    f,
+                    "server responded with status {}: {}",
+                    status,
+                    body.trim()
+                )
+            }
+            Error::Transport(e) => write!(f, "request failed: {}", e),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// Minimal Advent of Code client that authenticates with a session cookie.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    /// File storing the time of the last request, used to space out requests across runs.
+    throttle_file: Option<PathBuf>,
+}
+
+impl Client {
+    /// Creates a client without request throttling.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(&user_agent())
+                .timeout(REQUEST_TIMEOUT)
+                .redirects(0)
+                .build(),
+            throttle_file: None,
+        }
+    }
+
+    /// Creates a client for `AOC_BASE_URL` (or adventofcode.com) with the configured session.
+    /// Requests are spaced at least `MIN_REQUEST_INTERVAL` apart, even across runs.
+    pub fn from_env() -> Result<Self, Error> {
+        let base_url = env::var(BASE_URL_ENV_VAR).unwrap_or_else(|_| BASE_URL.to_string());
+        let mut client = Client::new(&base_url, &read_session()?);
+        client.throttle_file = Some(env::temp_dir().join("advent_of_code_last_request"));
+        Ok(client)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        self.throttle();
+        let result = self.request("GET", path).call();
+        self.handle(result)
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        self.throttle();
+        let result = self.request("POST", path).send_form(form);
+        self.handle(result)
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.agent
+            .request(method, &format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    fn handle(&self, result: Result<ureq::Response, ureq::Error>) -> Result<String, Error> {
+        self.record_request();
+
+        match result {
+            // the server redirects missing and invalid sessions to the login page. Redirects are
+            // not followed, so they arrive here instead of as an error.
+            Ok(response) if (300..400).contains(&response.status()) => Err(Error::Unauthorized),
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(match status {
+                    404 => Error::NotFound,
+                    // it errors on some of them instead.
+                    400 => Error::Unauthorized,
+                    // it also fails with 500 on some invalid sessions, anything else is an outage.
+                    500 if self.session.is_empty() || body.to_lowercase().contains("log in") => {
+                        Error::Unauthorized
+                    }
+                    _ => Error::Status(status, body),
+                })
+            }
+            Err(e) => Err(Error::Transport(e.to_string())),
+        }
+    }
+
+    fn throttle(&self) {
+        let last_request = self
+            .throttle_file
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| contents.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+
+        if let Some(last_request) = last_request {
+            let wait = (last_request + MIN_REQUEST_INTERVAL).saturating_sub(now());
+            if !wait.is_zero() {
+                eprintln!("Waiting {:.1?} to avoid flooding the server...", wait);
+                thread::sleep(wait);
+            }
+        }
+    }
+
+    fn record_request(&self) {
+        if let Some(path) = &self.throttle_file {
+            let _ = fs::write(path, now().as_millis().to_string());
+        }
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// Reads the session cookie from `AOC_SESSION` or `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, Error> {
+    if let Ok(session) = env::var(SESSION_ENV_VAR) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .ok_or(Error::MissingSession)?;
+    match fs::read_to_string(PathBuf::from(home).join(SESSION_FILE)) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(Error::MissingSession),
+    }
+}
+
+/// Returns `AOC_YEAR` if set. Otherwise the current year during December and the previous
+/// year before that, based on the puzzle unlock time zone (UTC-5).
+pub fn default_year() -> u16 {
+    if let Some(year) = env::var(YEAR_ENV_VAR).ok().and_then(|y| y.parse().ok()) {
+        return year;
+    }
+
+    let days = (now().as_secs() as i64 - 5 * 3600).div_euclid(86400);
+    let (year, month) = civil_from_days(days);
+    if month == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// Converts days since 1970-01-01 to `(year, month)`.
+/// See: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (u16, u8) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year as u16, month as u8)
+}
+
+/// A local stand-in for the Advent of Code server, used to exercise the client offline.
+#[cfg(test)]
+pub(crate) mod test_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Answers one connection per response and returns the raw requests it received.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                    }
+                    let mut body_buf = vec![0; content_length];
+                    reader.read_exact(&mut body_buf).unwrap();
+                    request.push_str(&String::from_utf8(body_buf).unwrap());
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_server::serve;
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = serve(vec![(200, "1000\n2000\n")]);
+        let client = Client::new(&base_url, "abc123\n");
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+        assert!(requests[0].contains(&format!("User-Agent: {}\r\n", user_agent())));
+    }
+
+    #[test]
+    fn test_errors() {
+        let (base_url, server) = serve(vec![
+            (404, "Not Found"),
+            (302, ""),
+            (400, "Puzzle inputs differ by user."),
+            (503, "Service Unavailable"),
+            (500, "Please log in to get your puzzle input."),
+            (500, "Internal Server Error"),
+        ]);
+        let client = Client::new(&base_url, "abc123");
+
+        assert!(matches!(client.input(2022, 26), Err(Error::NotFound)));
+        assert!(matches!(client.input(2022, 1), Err(Error::Unauthorized)));
+        assert!(matches!(client.input(2022, 1), Err(Error::Unauthorized)));
+        assert!(matches!(client.input(2022, 1), Err(Error::Status(503, _))));
+        assert!(matches!(client.input(2022, 1), Err(Error::Unauthorized)));
+        assert!(matches!(client.input(2022, 1), Err(Error::Status(500, _))));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1));
+        assert_eq!(civil_from_days(19327), (2022, 12));
+        assert_eq!(civil_from_days(19357), (2022, 12));
+        assert_eq!(civil_from_days(19358), (2023, 1));
+    }
+}
@@ -2,3 +2,172 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+
+pub mod graph;
+pub mod grid;
+pub mod point;
+
+pub use graph::GridGraph;
+pub use grid::Grid;
+pub use point::{Direction, Point2, Point3};
+
+/// Glyphs of the 6 pixel high font, e.g. used by 2016/08, 2019/08 and 2022/10.
+#[rustfmt::skip]
+const FONT_6: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Glyphs of the 10 pixel high font, e.g. used by 2018/10.
+#[rustfmt::skip]
+const FONT_10: &[(char, [&str; 10])] = &[
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+fn lit_pixels<S: AsRef<str>>(rows: &[S]) -> Vec<Vec<bool>> {
+    rows.iter()
+        .map(|row| row.as_ref().chars().map(is_lit).collect())
+        .collect()
+}
+
+/// Splits a picture into glyphs, i.e. runs of columns that contain a lit pixel.
+fn glyphs(pixels: &[Vec<bool>]) -> Vec<Vec<String>> {
+    let width = pixels.iter().map(Vec::len).max().unwrap_or(0);
+    let lit_col = |col: usize| pixels.iter().any(|row| row.get(col) == Some(&true));
+
+    let mut glyphs = Vec::new();
+    let mut col = 0;
+    while col < width {
+        if !lit_col(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < width && lit_col(col) {
+            col += 1;
+        }
+        glyphs.push(
+            pixels
+                .iter()
+                .map(|row| {
+                    (start..col)
+                        .map(|c| if row.get(c) == Some(&true) { '#' } else { '.' })
+                        .collect()
+                })
+                .collect(),
+        );
+    }
+    glyphs
+}
+
+fn recognize<const H: usize>(font: &[(char, [&str; H])], glyph: &[String]) -> Option<char> {
+    font.iter()
+        .find(|(_, rows)| glyphs(&lit_pixels(rows)).first().map(Vec::as_slice) == Some(glyph))
+        .map(|(letter, _)| *letter)
+}
+
+/// Reads capital letters from ASCII-art output such as a rendered CRT screen.
+/// Lit pixels are `#` or `█`, anything else is unlit. Letters are separated by unlit columns.
+/// Supports the 6 and 10 pixel high fonts used by Advent of Code.
+/// Returns `None` if the picture contains anything that is not a known letter.
+///
+/// ```
+/// use advent_of_code::helpers::ocr;
+/// let screen = "#..#.####\n#..#.#...\n####.###.\n#..#.#...\n#..#.#...\n#..#.####\n";
+/// assert_eq!(ocr(screen), Some("HE".to_string()));
+/// ```
+pub fn ocr(picture: &str) -> Option<String> {
+    let lines: Vec<&str> = picture.lines().collect();
+    let first = lines.iter().position(|line| line.chars().any(is_lit))?;
+    let last = lines.iter().rposition(|line| line.chars().any(is_lit))?;
+    let pixels = lit_pixels(&lines[first..=last]);
+
+    glyphs(&pixels)
+        .iter()
+        .map(|glyph| match pixels.len() {
+            6 => recognize(FONT_6, glyph),
+            10 => recognize(FONT_10, glyph),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render<const H: usize>(font: &[(char, [&str; H])], word: &str, spacing: usize) -> String {
+        (0..H)
+            .map(|row| {
+                word.chars()
+                    .map(|letter| {
+                        let (_, rows) = font.iter().find(|(l, _)| *l == letter).unwrap();
+                        format!("{}{}", rows[row], ".".repeat(spacing))
+                    })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ocr_font_6() {
+        let alphabet: String = FONT_6.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(ocr(&render(FONT_6, &alphabet, 1)), Some(alphabet));
+
+        assert_eq!(
+            ocr(&render(FONT_6, "EHZ", 1).replace('#', "█").replace('.', " ")),
+            Some("EHZ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_ocr_font_10() {
+        let alphabet: String = FONT_10.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(ocr(&render(FONT_10, &alphabet, 2)), Some(alphabet));
+    }
+
+    #[test]
+    fn test_ocr_unknown() {
+        assert_eq!(ocr(""), None);
+        assert_eq!(ocr("#.#\n.#.\n#.#\n"), None);
+        assert_eq!(
+            ocr("##..##..\n###...##\n####....\n#####...\n######..\n#######.\n"),
+            None
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use std::fmt;
+
+use pathfinding::prelude::{bfs, dijkstra_all};
+
+use super::grid::{Grid, Pos};
+use super::point::Direction;
+
+/// A [`Grid`] seen as a graph: each cell is a node, with an edge to each orthogonal neighbour
+/// that `can_move(from, to)` allows stepping onto.
+///
+/// Searches take any number of start positions, so "the shortest path from any `a`" is a
+/// single search instead of one per start.
+///
+/// ```
+/// use advent_of_code::helpers::{Grid, GridGraph};
+/// let grid: Grid<char> = "S.#\n..#\n.#E\n...".parse().unwrap();
+/// let graph = GridGraph::new(&grid, |_, to| *to != '#');
+/// let path = graph.shortest_path([(0, 0)], |pos| pos == (2, 2)).unwrap();
+/// assert_eq!(path.len() - 1, 6);
+/// ```
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    can_move: F,
+}
+
+impl<'a, T, F: Fn(&T, &T) -> bool> GridGraph<'a, T, F> {
+    pub fn new(grid: &'a Grid<T>, can_move: F) -> Self {
+        GridGraph { grid, can_move }
+    }
+
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// Returns the same graph with every edge pointing the other way, e.g. to search from the
+    /// goal back to all possible starts.
+    pub fn reversed(&self) -> GridGraph<'a, T, impl Fn(&T, &T) -> bool + '_> {
+        GridGraph::new(self.grid, move |from: &T, to: &T| (self.can_move)(to, from))
+    }
+
+    /// Iterates over the positions that can be reached from `pos` in one step, none if `pos` is
+    /// outside the grid.
+    pub fn successors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let grid = self.grid;
+        let cell = grid.get(pos);
+        grid.neighbours4(pos)
+            .filter(move |next| cell.is_some_and(|cell| (self.can_move)(cell, &grid[*next])))
+    }
+
+    /// Finds a shortest path from any of `starts` to a position for which `is_goal` returns
+    /// true. The path includes both ends, so it takes `path.len() - 1` steps. Starts outside
+    /// the grid are ignored.
+    pub fn shortest_path(
+        &self,
+        starts: impl IntoIterator<Item = Pos>,
+        mut is_goal: impl FnMut(Pos) -> bool,
+    ) -> Option<Vec<Pos>> {
+        // `None` is a virtual node in front of all starts, which turns the search into a
+        // single-source one.
+        let starts: Vec<Option<Pos>> = starts
+            .into_iter()
+            .filter(|pos| self.grid.contains(*pos))
+            .map(Some)
+            .collect();
+        let path = bfs(
+            &None,
+            |node: &Option<Pos>| match node {
+                None => starts.clone(),
+                Some(pos) => self.successors(*pos).map(Some).collect(),
+            },
+            |node| node.is_some_and(&mut is_goal),
+        )?;
+        Some(path.into_iter().flatten().collect())
+    }
+
+    /// Searches the whole graph from all of `starts` and returns the distance of every
+    /// position, together with a shortest path to it. Starts outside the grid are ignored.
+    pub fn distances(&self, starts: impl IntoIterator<Item = Pos>) -> Distances {
+        let starts: Vec<(Option<Pos>, usize)> = starts
+            .into_iter()
+            .filter(|pos| self.grid.contains(*pos))
+            .map(|pos| (Some(pos), 0))
+            .collect();
+        let reached = dijkstra_all(&None, |node: &Option<Pos>| match node {
+            None => starts.clone(),
+            Some(pos) => self.successors(*pos).map(|next| (Some(next), 1)).collect(),
+        });
+
+        let mut distances = Grid::new(self.grid.width(), self.grid.height(), None);
+        let mut parents = Grid::new(self.grid.width(), self.grid.height(), None);
+        for (node, (parent, distance)) in reached {
+            if let Some(pos) = node {
+                distances[pos] = Some(distance);
+                parents[pos] = parent;
+            }
+        }
+        Distances { distances, parents }
+    }
+}
+
+/// Result of [`GridGraph::distances`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distances {
+    distances: Grid<Option<usize>>,
+    /// Previous position on a shortest path, `None` for the starts.
+    parents: Grid<Option<Pos>>,
+}
+
+impl Distances {
+    /// Returns the number of steps from the nearest start to `pos`, `None` if it is
+    /// unreachable or outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<usize> {
+        self.distances.get(pos).copied().flatten()
+    }
+
+    /// Returns the distance of every position, `None` for unreachable ones.
+    pub fn grid(&self) -> &Grid<Option<usize>> {
+        &self.distances
+    }
+
+    /// Returns a shortest path from the nearest start to `pos`, including both ends.
+    pub fn path_to(&self, pos: Pos) -> Option<Vec<Pos>> {
+        self.get(pos)?;
+        let mut path: Vec<Pos> =
+            std::iter::successors(Some(pos), |pos| self.parents[*pos]).collect();
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Renders `grid` with `path` drawn over it as arrows `^>v<` pointing to the next step.
+/// The last position of the path keeps its cell. Cells should print as a single character.
+///
+/// ```
+/// use advent_of_code::helpers::{graph::render_path, Grid};
+/// let grid: Grid<char> = "S..\n..E".parse().unwrap();
+/// assert_eq!(render_path(&grid, &[(0, 0), (1, 0), (1, 1), (2, 1)]), ">v.\n.>E");
+/// ```
+pub fn render_path<T: fmt::Display>(grid: &Grid<T>, path: &[Pos]) -> String {
+    let mut cells = grid.map(|cell| cell.to_string());
+    for step in path.windows(2) {
+        let [(x, y), (next_x, next_y)] = [step[0], step[1]];
+        let delta = (next_x as isize - x as isize, next_y as isize - y as isize);
+        cells[step[0]] = Direction::from_delta(delta)
+            .map_or('*', Direction::arrow)
+            .to_string();
+    }
+    cells.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        // a wall between the two rooms, with a gap only at the bottom.
+        "a.#.\n..#b\n.a..".parse().unwrap()
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let grid = example();
+        let graph = GridGraph::new(&grid, |_, to| *to != '#');
+        let goal = grid.find(&'b').unwrap();
+
+        let path = graph.shortest_path([(0, 0)], |pos| pos == goal).unwrap();
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+        assert_eq!(path.len() - 1, 6);
+        assert_eq!(render_path(&grid, &path), ">v#.\n.v#b\n.>>^");
+
+        let from_any_a = graph
+            .shortest_path(grid.find_all(&'a'), |pos| pos == goal)
+            .unwrap();
+        assert_eq!(from_any_a, [(1, 2), (2, 2), (3, 2), (3, 1)]);
+
+        assert_eq!(graph.shortest_path([], |pos| pos == goal), None);
+        assert_eq!(graph.shortest_path([(4, 0)], |_| true), None);
+        assert_eq!(graph.successors((4, 0)).count(), 0);
+        assert_eq!(
+            graph.shortest_path([(9, 9), (3, 0)], |pos| pos == goal),
+            Some(vec![(3, 0), (3, 1)])
+        );
+        assert_eq!(graph.shortest_path([(0, 0)], |pos| pos == (2, 0)), None);
+    }
+
+    #[test]
+    fn test_distances() {
+        let grid = example();
+        let graph = GridGraph::new(&grid, |_, to| *to != '#');
+
+        let distances = graph.distances(grid.find_all(&'a'));
+        assert_eq!(distances.get((0, 0)), Some(0));
+        assert_eq!(distances.get((1, 1)), Some(1));
+        assert_eq!(distances.get((3, 0)), Some(4));
+        assert_eq!(distances.get((2, 0)), None);
+        assert_eq!(distances.get((9, 9)), None);
+        assert_eq!(graph.distances([(9, 9)]).grid().find_all(&None).count(), 12);
+        assert_eq!(
+            distances.path_to((3, 2)),
+            Some(vec![(1, 2), (2, 2), (3, 2)])
+        );
+        assert_eq!(distances.path_to((2, 1)), None);
+
+        let map = distances
+            .grid()
+            .map(|d| d.map_or('#', |d| (b'0' + d as u8) as char));
+        assert_eq!(map.to_string(), "01#4\n11#3\n1012");
+    }
+
+    #[test]
+    fn test_reversed() {
+        let grid: Grid<u8> = Grid::from_rows(vec![vec![1, 2, 3, 5]]).unwrap();
+        // climb at most one step up, drop any height.
+        let graph = GridGraph::new(&grid, |from, to| *to <= from + 1);
+        assert_eq!(graph.successors((2, 0)).collect::<Vec<_>>(), [(1, 0)]);
+        assert_eq!(
+            graph.reversed().successors((2, 0)).collect::<Vec<_>>(),
+            [(3, 0), (1, 0)]
+        );
+        assert_eq!(graph.shortest_path([(0, 0)], |pos| pos == (3, 0)), None);
+
+        let distances = graph.reversed().distances([(2, 0)]);
+        assert_eq!(distances.get((3, 0)), Some(1));
+        assert_eq!(
+            distances.path_to((0, 0)),
+            Some(vec![(2, 0), (1, 0), (0, 0)])
+        );
+    }
+}
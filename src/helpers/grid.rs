@@ -0,0 +1,290 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+
+/// Position of a cell as `(x, y)`, i.e. column and row. `(0, 0)` is the top left cell.
+pub type Pos = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours, clockwise starting with the one above.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all 8 neighbours, clockwise starting with the one above.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// ```
+/// use advent_of_code::helpers::Grid;
+/// let grid: Grid<char> = "#.#\n.S.".parse().unwrap();
+/// assert_eq!(grid.find(&'S'), Some((1, 1)));
+/// assert_eq!(grid.neighbours4((1, 1)).count(), 3);
+/// assert_eq!(grid.to_string(), "#.#\n.S.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` × `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(format!(
+                "row {} has {} cells, expected {}",
+                y + 1,
+                rows[y].len(),
+                width
+            ));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a character map, one row per line, converting each character with `cell`.
+    /// Use [`crate::parse::grid`] if `cell` may reject characters.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        parse::grid(input, "any character", |c| Some(cell(c)))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.1 * self.width + pos.0])
+    }
+
+    /// Returns the position `offset` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Iterates over all positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over all cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Iterates over the orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS_4
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// Iterates over the orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// Returns row `y` from left to right.
+    ///
+    /// # Panics
+    /// If `y` is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} is outside the grid", y);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Iterates over column `x` from top to bottom.
+    ///
+    /// # Panics
+    /// If `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0, an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over the cells from `pos` in steps of `step` until the edge of the grid,
+    /// not including `pos` itself.
+    pub fn ray(&self, pos: Pos, step: (isize, isize)) -> impl Iterator<Item = (Pos, &T)> {
+        std::iter::successors(self.offset(pos, step), move |pos| self.offset(*pos, step))
+            .take_while(move |_| step != (0, 0))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    /// Returns the position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// Iterates over the positions of all cells equal to `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Returns a grid of the same size with `f` applied to each cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, |c| c)
+    }
+}
+
+/// Renders one line per row, without a trailing newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        "abc\ndef\nghi\njkl".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\nghi\njkl");
+
+        let error = "ab\nc".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "a row of 2 cells");
+
+        let empty: Grid<char> = "".parse().unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.rows().count(), 0);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        let neighbours: Vec<Pos> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(neighbours, [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 3)).count(), 3);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = example();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cfil");
+        assert_eq!(grid.rows().count(), 4);
+
+        let ray = |pos, step| grid.ray(pos, step).map(|(_, c)| *c).collect::<String>();
+        assert_eq!(ray((0, 0), (1, 1)), "ei");
+        assert_eq!(ray((1, 3), (0, -1)), "heb");
+        assert_eq!(ray((0, 0), (-1, 0)), "");
+        assert_eq!(ray((1, 1), (0, 0)), "");
+    }
+
+    #[test]
+    fn test_find_and_update() {
+        let mut grid = example().map(|c| *c == 'e' || *c == 'k');
+        assert_eq!(grid.find(&true), Some((1, 1)));
+        assert_eq!(grid.find_all(&true).collect::<Vec<_>>(), [(1, 1), (1, 3)]);
+
+        grid[(1, 1)] = false;
+        *grid.get_mut((0, 0)).unwrap() = true;
+        assert_eq!(grid.find(&true), Some((0, 0)));
+        assert_eq!(Grid::new(2, 1, 0).to_string(), "00");
+    }
+}
@@ -0,0 +1,297 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use super::grid::Pos;
+
+/// A point on a 2D plane. Like in [`super::Grid`], `y` grows downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point in 3D space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// Implements component-wise arithmetic and distances for a point type.
+macro_rules! point {
+    ($point:ident { $($field:ident),+ }) => {
+        impl $point {
+            pub const ORIGIN: $point = $point { $($field: 0),+ };
+
+            pub const fn new($($field: i64),+) -> Self {
+                $point { $($field),+ }
+            }
+
+            /// Sum of the absolute differences of the coordinates, i.e. the number of
+            /// orthogonal steps between the points.
+            pub fn manhattan(self, other: Self) -> i64 {
+                0 $(+ (self.$field - other.$field).abs())+
+            }
+
+            /// Largest absolute difference of the coordinates, i.e. the number of king's moves
+            /// between the points.
+            pub fn chebyshev(self, other: Self) -> i64 {
+                0 $(.max((self.$field - other.$field).abs()))+
+            }
+
+            /// Replaces each coordinate with its sign, e.g. to step towards another point.
+            pub fn signum(self) -> Self {
+                $point { $($field: self.$field.signum()),+ }
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, factor: i64) -> Self {
+                $point { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point!(Point2 { x, y });
+point!(Point3 { x, y, z });
+
+impl Point2 {
+    /// Returns the grid position of this point, if both coordinates are non-negative.
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+
+    /// Iterates over the 4 orthogonal neighbours, in the order of [`Direction::ALL`].
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL.into_iter().map(move |dir| self + dir)
+    }
+}
+
+impl From<Pos> for Point2 {
+    fn from((x, y): Pos) -> Self {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+impl Add<Direction> for Point2 {
+    type Output = Self;
+
+    fn add(self, dir: Direction) -> Self {
+        self + dir.to_point()
+    }
+}
+
+impl AddAssign<Direction> for Point2 {
+    fn add_assign(&mut self, dir: Direction) {
+        *self = *self + dir;
+    }
+}
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// One of the 4 orthogonal directions on a grid, where up is towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting with `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Offset of a single step as `(dx, dy)`, e.g. for [`super::Grid::offset`] or
+    /// [`super::Grid::ray`].
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    /// Returns the direction of a single step `(dx, dy)`, `None` for any other offset.
+    pub fn from_delta(delta: (isize, isize)) -> Option<Self> {
+        Direction::ALL.into_iter().find(|dir| dir.delta() == delta)
+    }
+
+    /// Returns the arrow `^`, `>`, `v` or `<` pointing in this direction.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    pub fn to_point(self) -> Point2 {
+        let (dx, dy) = self.delta();
+        Point2::new(dx as i64, dy as i64)
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    /// Accepts `U/R/D/L`, compass points `N/E/S/W` and arrows `^>v<` or `↑→↓←`,
+    /// in upper or lower case.
+    fn try_from(c: char) -> Result<Self, String> {
+        match c.to_ascii_uppercase() {
+            'U' | 'N' | '^' | '↑' => Ok(Direction::Up),
+            'R' | 'E' | '>' | '→' => Ok(Direction::Right),
+            'D' | 'S' | 'V' | '↓' => Ok(Direction::Down),
+            'L' | 'W' | '<' | '←' => Ok(Direction::Left),
+            _ => Err(format!("invalid direction '{}'", c)),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    /// Parses a single character, see [`Direction::try_from`].
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(format!("invalid direction \"{}\"", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(4, 2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, 4));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!((b - a).signum(), Point2::new(1, 1));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+
+        let mut c = Point3::new(1, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c -= Point3::new(0, 0, 5);
+        assert_eq!(c, Point3::new(2, 3, -1));
+        assert_eq!(c.manhattan(Point3::ORIGIN), 6);
+        assert_eq!(c.chebyshev(Point3::ORIGIN), 3);
+        assert_eq!(c.to_string(), "(2, 3, -1)");
+    }
+
+    #[test]
+    fn test_grid_positions() {
+        assert_eq!(Point2::new(3, 0).to_pos(), Some((3, 0)));
+        assert_eq!(Point2::new(3, -1).to_pos(), None);
+        assert_eq!(Point2::from((2, 5)), Point2::new(2, 5));
+
+        let neighbours: Vec<Point2> = Point2::ORIGIN.neighbours4().collect();
+        assert_eq!(
+            neighbours,
+            [
+                Point2::new(0, -1),
+                Point2::new(1, 0),
+                Point2::new(0, 1),
+                Point2::new(-1, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_direction() {
+        let parsed: Vec<Direction> = "U R D L N E S W ^ > v < ↑ → ↓ ←"
+            .split(' ')
+            .map(|dir| dir.parse().unwrap())
+            .collect();
+        assert_eq!(parsed, Direction::ALL.repeat(4));
+        assert!("X".parse::<Direction>().is_err());
+        assert!("UP".parse::<Direction>().is_err());
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Point2::new(2, 2) + Direction::Up, Point2::new(2, 1));
+        assert_eq!(Direction::Down.delta(), (0, 1));
+        assert_eq!(Direction::from_delta((-1, 0)), Some(Direction::Left));
+        assert_eq!(Direction::from_delta((1, 1)), None);
+        for dir in Direction::ALL {
+            assert_eq!(Direction::try_from(dir.arrow()), Ok(dir));
+        }
+    }
+}
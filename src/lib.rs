@@ -3,123 +3,786 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
+use std::fmt::{self, Write};
 use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
+use bench::BenchStats;
+use memory::MemoryStats;
+use options::Options;
+
+pub use answer::{Answer, IntoAnswer};
+
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod client;
 pub mod helpers;
+pub mod memory;
+pub mod options;
+pub mod parse;
+pub mod profile;
+pub mod puzzle;
+pub mod readme;
+pub mod runner;
+pub mod submit;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
+/// A type-erased solver for one part of a day, as stored in the registry.
+pub type Solver = fn(&str) -> Option<Answer>;
 
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
+/// Registry entry of a solved day, used by the runners to call solutions in-process.
+pub struct Day {
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+impl Day {
+    /// Returns the parts selected with `--part`, with their solvers.
+    pub fn selected_parts(&self) -> impl Iterator<Item = (u8, Solver)> {
+        [(1, self.part_one), (2, self.part_two)]
+            .into_iter()
+            .filter(|(part, _)| Options::current().runs_part(*part))
+    }
+}
+
+/// Registry entry of a year, holding the days solved so far.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+/// Collects the day modules of a year into its `DAYS` registry.
+macro_rules! days {
+    ($($day:literal => $module:ident),* $(,)?) => {
+        pub const DAYS: &[$crate::Day] = &[$(
+            $crate::Day {
+                day: $day,
+                part_one: |input| $crate::IntoAnswer::into_answer($module::part_one(input)),
+                part_two: |input| $crate::IntoAnswer::into_answer($module::part_two(input)),
+            },
+        )*];
+    };
+}
+
+/// Collects the year modules into the `YEARS` registry.
+macro_rules! years {
+    ($($year:literal => $module:ident),* $(,)?) => {
+        pub const YEARS: &[Year] = &[$(
+            Year {
+                year: $year,
+                days: $module::DAYS,
+            },
+        )*];
+    };
+}
+
+// `cargo scaffold` registers new years here, their days are registered in `src/yYYYY/mod.rs`.
+pub mod y2022;
+
+years! {
+    2022 => y2022,
+}
+
+/// Looks up a day in the registry.
+pub fn find_day(year: u16, day: u8) -> Option<&'static Day> {
+    YEARS
+        .iter()
+        .find(|y| y.year == year)?
+        .days
+        .iter()
+        .find(|d| d.day == day)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The solver returned an answer, but no accepted answer is recorded.
+    Solved,
+    /// The solver returned `None`.
+    Unsolved,
+    /// The answer matches the recorded answer.
+    Correct,
+    /// The answer differs from the recorded answer.
+    Wrong,
+    /// The solver panicked.
+    Panicked,
+    /// The solver did not finish within the timeout.
+    TimedOut,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Correct => "correct",
+            Status::Wrong => "wrong",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+        }
+    }
+}
+
+/// Reason a solver did not return, see [`runner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The solver panicked with this message.
+    Panicked(String),
+    /// The solver was abandoned after this timeout.
+    TimedOut(Duration),
+}
+
+/// Outcome of running a single part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// Accepted answer from `src/yYYYY/answers/NN.toml`, if recorded.
+    pub expected: Option<String>,
+    /// Time spent in the solver. In bench mode, this is the median of all samples.
+    pub elapsed: Duration,
+    pub bench: Option<BenchStats>,
+    /// Median of a previous bench run, if a baseline was given.
+    pub baseline: Option<Duration>,
+    /// Set if the solver panicked or timed out, `answer` is `None` then.
+    pub failure: Option<Failure>,
+    /// Heap usage of a single run, if built with the `memory` feature.
+    pub memory: Option<MemoryStats>,
+}
+
+impl PartResult {
+    pub fn status(&self) -> Status {
+        match self.failure {
+            Some(Failure::Panicked(_)) => return Status::Panicked,
+            Some(Failure::TimedOut(_)) => return Status::TimedOut,
+            None => {}
+        }
+        match (&self.answer, &self.expected) {
+            (None, _) => Status::Unsolved,
+            (Some(_), None) => Status::Solved,
+            (Some(answer), Some(expected)) if answer.matches(expected) => Status::Correct,
+            (Some(_), Some(_)) => Status::Wrong,
+        }
+    }
+
+    /// Formats the result as a one-line JSON object, e.g.
+    /// `{"year":2022,"day":1,"part":1,"answer":"24000","elapsed_ns":1337,"status":"solved"}`.
+    /// `answer` is `null` if the solver returned `None`. Wrong answers add an `expected` field,
+    /// panics add a `message` field, bench runs add a `bench` object and builds with the
+    /// `memory` feature a `memory` object.
+    pub fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Some(answer) => json_string(&answer.to_string()),
+            None => "null".to_string(),
+        };
+        let mut out = format!(
+            r#"{{"year":{},"day":{},"part":{},"answer":{},"elapsed_ns":{},"status":"{}""#,
+            self.year,
+            self.day,
+            self.part,
+            answer,
+            self.elapsed.as_nanos(),
+            self.status().as_str()
+        );
+        if let (Status::Wrong, Some(expected)) = (self.status(), &self.expected) {
+            write!(out, r#","expected":{}"#, json_string(expected)).unwrap();
+        }
+        if let Some(Failure::Panicked(message)) = &self.failure {
+            write!(out, r#","message":{}"#, json_string(message)).unwrap();
+        }
+        if let Some(memory) = &self.memory {
+            write!(
+                out,
+                r#","memory":{{"peak_bytes":{},"allocations":{}}}"#,
+                memory.peak_bytes, memory.allocations
+            )
+            .unwrap();
+        }
+        if let Some(stats) = &self.bench {
+            write!(
+                out,
+                r#","bench":{{"samples":{},"min_ns":{},"median_ns":{},"mean_ns":{},"stddev_ns":{}"#,
+                stats.samples,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            )
+            .unwrap();
+            if let Some(baseline) = self.baseline {
+                write!(out, r#","baseline_median_ns":{}"#, baseline.as_nanos()).unwrap();
+            }
+            out.push('}');
+        }
+        out.push('}');
+        out
+    }
+
+    /// Prints the result, either formatted or as a JSON record.
+    pub fn report(&self) {
+        if Options::current().json {
+            println!("{}", self.to_json());
+        } else {
+            self.print();
+        }
+    }
+
+    /// Prints the answer with its status marker and timing.
+    pub fn print(&self) {
+        match &self.failure {
+            Some(Failure::Panicked(message)) => {
+                println!("{}panicked:{} {}", ANSI_BOLD, ANSI_RESET, message);
+                return;
+            }
+            Some(Failure::TimedOut(timeout)) => {
+                println!(
+                    "{}timed out{} after {:.2?}.",
+                    ANSI_BOLD, ANSI_RESET, timeout
+                );
+                return;
+            }
+            None => {}
+        }
+
+        let answer = match &self.answer {
+            Some(answer) => answer,
+            None => {
+                println!("not solved.");
+                return;
+            }
+        };
+
+        let answer = match self.status() {
+            Status::Correct => format!("{} ✓", answer),
+            Status::Wrong => format!("{} ✗", answer),
+            _ => answer.to_string(),
+        };
+
+        match &self.bench {
+            Some(stats) => {
+                print!(
+                    "{} {}(median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, samples: {})",
+                    answer,
+                    ANSI_ITALIC,
+                    stats.median,
+                    stats.mean,
+                    stats.stddev,
+                    stats.min,
+                    stats.samples
+                );
+                if let Some(baseline) = self.baseline {
+                    let change =
+                        (stats.median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+                    print!(" ({:+.1}% vs. baseline {:.2?})", change, baseline);
                 }
-                None => {
-                    println!("not solved.")
+                if let Some(memory) = &self.memory {
+                    print!(" ({})", memory);
                 }
+                println!("{}", ANSI_RESET);
+            }
+            None => {
+                print!("{} {}(elapsed: {:.2?}", answer, ANSI_ITALIC, self.elapsed);
+                if let Some(memory) = &self.memory {
+                    print!(", {}", memory);
+                }
+                println!("){}", ANSI_RESET);
             }
         }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+        if let (Status::Wrong, Some(expected)) = (self.status(), &self.expected) {
+            println!(
+                "{}Wrong answer, expected:{} {}",
+                ANSI_BOLD, ANSI_RESET, expected
+            );
+        }
+    }
+}
+
+/// Runs the parts of `day` selected with `--part` on `input`.
+pub fn run_day(year: u16, day: &Day, input: &str) -> Vec<PartResult> {
+    day.selected_parts()
+        .map(|(part, solver)| run_part(year, day.day, part, solver, input))
+        .collect()
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Runs `solver` on `input` and prints its result, either formatted or as a JSON record.
+/// With `--bench`, the solver is run repeatedly and timing statistics are reported.
+pub fn run_part<R: IntoAnswer>(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> R,
+    input: &str,
+) -> PartResult {
+    print_part_header(part);
+    let result = solve_part(year, day, part, solver, input);
+    result.report();
+    result
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Prints the header of a part, unless JSON records are printed.
+pub fn print_part_header(part: u8) {
+    if !Options::current().json {
+        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Runs `solver` on `input` like [`run_part`], without printing its result.
+pub fn solve_part<R: IntoAnswer>(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> R,
+    input: &str,
+) -> PartResult {
+    let run = measure(solver, input, Options::current().bench);
+    let mut result = check_answer(year, day, part, run.answer.into_answer(), run.elapsed);
+    result.memory = run.memory;
+    set_bench(&mut result, run.bench);
+    result
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Runs `solver` on `input` once and checks its answer, ignoring `--bench`.
+/// Timings are only reliable if nothing else runs at the same time, see [`time_part`].
+pub fn check_part<R: IntoAnswer>(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> R,
+    input: &str,
+) -> PartResult {
+    let run = measure(solver, input, false);
+    let mut result = check_answer(year, day, part, run.answer.into_answer(), run.elapsed);
+    result.memory = run.memory;
+    result
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+/// Measures `solver` on `input` again and replaces the timings of `result`.
+/// With `--bench`, the solver is run repeatedly.
+pub fn time_part<R>(result: &mut PartResult, solver: impl Fn(&str) -> R, input: &str) {
+    let run = measure(solver, input, Options::current().bench);
+    result.elapsed = run.elapsed;
+    set_bench(result, run.bench);
+}
+
+/// Answer and measurements of running a part.
+struct Run<R> {
+    answer: R,
+    elapsed: Duration,
+    bench: Option<BenchStats>,
+    memory: Option<MemoryStats>,
+}
+
+fn measure<R>(solver: impl Fn(&str) -> R, input: &str, bench: bool) -> Run<R> {
+    if bench {
+        // heap usage is the same for every run, count it on a run outside of the samples.
+        let (_, memory) = memory::measure(|| solver(input));
+        let (answer, stats) = bench::bench(solver, input);
+        Run {
+            answer,
+            elapsed: stats.median,
+            bench: Some(stats),
+            memory,
+        }
+    } else {
+        let ((answer, elapsed), memory) = memory::measure(|| {
+            let timer = Instant::now();
+            let answer = solver(input);
+            (answer, timer.elapsed())
+        });
+        Run {
+            answer,
+            elapsed,
+            bench: None,
+            memory,
+        }
+    }
+}
+
+/// Builds the result of a part, comparing `answer` to the accepted answer and recording it
+/// with `--save-answers`.
+fn check_answer(
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<Answer>,
+    elapsed: Duration,
+) -> PartResult {
+    let options = Options::current();
+    let expected = match expected_answers(year, day) {
+        Ok(answers) => answers.get(part).map(str::to_string),
+        Err(e) => {
+            eprintln!("Failed to read answers: {}", e);
+            None
+        }
+    };
+
+    // only the puzzle input has accepted answers.
+    let save = options.save_answers && options.puzzle_input();
+    if let (true, Some(answer), None) = (save, &answer, &expected) {
+        match answers::record_answer(year, day, part, &answer.to_string()) {
+            Ok(_) => eprintln!("Recorded answer for day {} part {}.", day, part),
+            Err(e) => eprintln!("Failed to record answer: {}", e),
+        }
+    }
+
+    PartResult {
+        year,
+        day,
+        part,
+        answer,
+        expected,
+        elapsed,
+        bench: None,
+        baseline: None,
+        failure: None,
+        memory: None,
+    }
+}
+
+/// Stores bench statistics in `result`, comparing them to `--baseline` and saving them
+/// with `--save-baseline`.
+fn set_bench(result: &mut PartResult, bench: Option<BenchStats>) {
+    let options = Options::current();
+    let key = (result.year, result.day, result.part);
+
+    result.baseline = options.baseline.as_ref().and_then(|path| {
+        bench::read_baseline(path)
+            .map_err(|e| eprintln!("Failed to read baseline \"{}\": {}", path.display(), e))
+            .ok()?
+            .get(&key)
+            .copied()
+    });
+
+    if let (Some(path), Some(stats)) = (&options.save_baseline, &bench) {
+        if let Err(e) = bench::update_baseline(path, key.0, key.1, key.2, stats.median) {
+            eprintln!("Failed to write baseline \"{}\": {}", path.display(), e);
+        }
+    }
+
+    result.bench = bench;
+}
+
+fn json_string(val: &str) -> String {
+    let mut out = String::with_capacity(val.len() + 2);
+    out.push('"');
+    for c in val.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                write!(out, "\\u{:04x}", c as u32).unwrap();
             }
+            c => out.push(c),
         }
-    })
+    }
+    out.push('"');
+    out
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Root of the project, so files are found no matter which directory a command runs in.
+pub fn root_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Directory holding the solutions and data files of a year, e.g. `src/y2022`.
+pub fn year_dir(year: u16) -> PathBuf {
+    root_dir().join("src").join(format!("y{}", year))
+}
+
+pub fn input_path(year: u16, folder: &str, day: u8) -> PathBuf {
+    year_dir(year).join(folder).join(format!("{:02}.txt", day))
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    /// The file exists but is empty, e.g. because it was scaffolded but not filled in yet.
+    Empty(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl InputError {
+    pub fn path(&self) -> &Path {
+        match self {
+            InputError::NotFound(path) | InputError::Empty(path) | InputError::Io(path, _) => path,
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "\"{}\" does not exist", path.display()),
+            InputError::Empty(path) => write!(f, "\"{}\" is empty", path.display()),
+            InputError::Io(path, e) => write!(f, "could not read \"{}\": {}", path.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Converts CRLF line endings to LF and strips trailing newlines,
+/// so inputs look the same no matter how they were saved.
+pub fn normalize_input(input: &str) -> String {
+    input
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+/// Reads and normalizes an input file. Empty files are treated as an error.
+pub fn read_input(path: &Path) -> Result<String, InputError> {
+    let input = match fs::read_to_string(path) {
+        Ok(input) => normalize_input(&input),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(InputError::NotFound(path.to_path_buf()))
+        }
+        Err(e) => return Err(InputError::Io(path.to_path_buf(), e)),
+    };
+    if input.is_empty() {
+        return Err(InputError::Empty(path.to_path_buf()));
+    }
+    Ok(input)
+}
+
+pub fn try_read_file(year: u16, folder: &str, day: u8) -> Result<String, InputError> {
+    read_input(&input_path(year, folder, day))
+}
+
+/// Path of the input selected with `--input` or `--example`, by default the puzzle input.
+/// `None` means stdin.
+pub fn selected_input_path(year: u16, day: u8) -> Option<PathBuf> {
+    let options = Options::current();
+    match &options.input {
+        Some(path) if path == Path::new("-") => None,
+        Some(path) => Some(path.clone()),
+        None if options.example => Some(input_path(year, "examples", day)),
+        None => Some(input_path(year, "inputs", day)),
+    }
+}
+
+/// Reads the input selected with `--input` or `--example`, by default the puzzle input.
+pub fn read_selected_input(year: u16, day: u8) -> Result<String, InputError> {
+    let path = match selected_input_path(year, day) {
+        Some(path) => return read_input(&path),
+        None => PathBuf::from("<stdin>"),
+    };
+    match io::read_to_string(io::stdin()) {
+        Ok(input) if input.trim().is_empty() => Err(InputError::Empty(path)),
+        Ok(input) => Ok(normalize_input(&input)),
+        Err(e) => Err(InputError::Io(path, e)),
+    }
+}
+
+/// Expected answers for the selected input: the accepted answers for the puzzle input,
+/// otherwise the `.toml` file next to the selected file, e.g. `examples/01.toml`.
+fn expected_answers(year: u16, day: u8) -> Result<answers::Answers, String> {
+    if Options::current().puzzle_input() {
+        return answers::read_answers(year, day);
+    }
+    match selected_input_path(year, day) {
+        Some(path) => answers::read_answers_file(&path.with_extension("toml")),
+        None => Ok(answers::Answers::default()),
+    }
+}
+
+/// Like `try_read_file`, but panics with the attempted path. Meant for tests.
+pub fn read_file(year: u16, folder: &str, day: u8) -> String {
+    try_read_file(year, folder, day).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Reads a named input of a day, e.g. `read_file_named(2022, "examples", 1, "2")` for `01-2.txt`.
+pub fn read_file_named(year: u16, folder: &str, day: u8, name: &str) -> String {
+    let path = year_dir(year)
+        .join(folder)
+        .join(format!("{:02}-{}.txt", day, name));
+    read_input(&path).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// An input of a day: its main file `NN.txt` or a named one like `NN-2.txt` or `NN-alice.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputFile {
+    /// `inputs` or `examples`.
+    pub folder: String,
+    /// `None` for the main file.
+    pub name: Option<String>,
+    pub path: PathBuf,
+}
+
+impl InputFile {
+    fn new(folder: &str, path: PathBuf, day: u8) -> Option<Self> {
+        let rest = path
+            .file_name()?
+            .to_str()?
+            .strip_prefix(&format!("{:02}", day))?
+            .strip_suffix(".txt")?;
+        let name = match rest {
+            "" => None,
+            _ => Some(rest.strip_prefix('-').filter(|name| !name.is_empty())?),
+        };
+        Some(InputFile {
+            folder: folder.to_string(),
+            name: name.map(str::to_string),
+            path,
+        })
+    }
+
+    /// Returns the expected answers of this input. For the main puzzle input, these are
+    /// the accepted answers. Other inputs pair with a `.toml` file next to them, e.g. `01-2.toml`.
+    pub fn expected(&self, year: u16, day: u8) -> Result<answers::Answers, String> {
+        if self.folder == "inputs" && self.name.is_none() {
+            answers::read_answers(year, day)
+        } else {
+            answers::read_answers_file(&self.path.with_extension("toml"))
+        }
+    }
+}
+
+/// Lists the inputs and examples of a day, main files first.
+pub fn input_files(year: u16, day: u8) -> Vec<InputFile> {
+    let mut files: Vec<InputFile> = ["inputs", "examples"]
+        .iter()
+        .flat_map(|folder| {
+            fs::read_dir(year_dir(year).join(folder))
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .filter_map(move |entry| InputFile::new(folder, entry.path(), day))
+        })
+        .collect();
+    files.sort_by(|a, b| (a.folder != "inputs", &a.name).cmp(&(b.folder != "inputs", &b.name)));
+    files
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn result(answer: Option<&str>, elapsed: Duration) -> PartResult {
+        PartResult {
+            year: 2022,
+            day: 1,
+            part: 2,
+            answer: answer.map(Answer::from),
+            expected: None,
+            elapsed,
+            bench: None,
+            baseline: None,
+            failure: None,
+            memory: None,
+        }
+    }
+
+    #[test]
+    fn test_normalize_input() {
+        assert_eq!(normalize_input("1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3");
+        assert_eq!(normalize_input("  1\n2\n\n\n"), "  1\n2");
+        assert_eq!(normalize_input("1 \n2 "), "1 \n2 ");
+    }
+
+    #[test]
+    fn test_read_input_errors() {
+        let missing = year_dir(2022).join("inputs").join("00-missing.txt");
+        assert!(matches!(read_input(&missing), Err(InputError::NotFound(path)) if path == missing));
+
+        let empty = std::env::temp_dir().join("advent_of_code_empty_input.txt");
+        fs::write(&empty, "\r\n").unwrap();
+        assert!(matches!(read_input(&empty), Err(InputError::Empty(_))));
+        fs::remove_file(&empty).unwrap();
+    }
+
+    #[test]
+    fn test_input_file_names() {
+        let name =
+            |file_name| InputFile::new("examples", PathBuf::from(file_name), 7).map(|f| f.name);
+        assert_eq!(name("07.txt"), Some(None));
+        assert_eq!(name("07-2.txt"), Some(Some("2".to_string())));
+        assert_eq!(name("07-alice.txt"), Some(Some("alice".to_string())));
+        assert_eq!(name("07-.txt"), None);
+        assert_eq!(name("077.txt"), None);
+        assert_eq!(name("17.txt"), None);
+        assert_eq!(name("07-2.toml"), None);
+    }
+
+    #[test]
+    fn test_json_record() {
+        assert_eq!(
+            result(Some("45000"), Duration::from_micros(12)).to_json(),
+            r#"{"year":2022,"day":1,"part":2,"answer":"45000","elapsed_ns":12000,"status":"solved"}"#
+        );
+
+        assert_eq!(
+            result(Some("#.\n\"a\"\t\u{1b}"), Duration::ZERO).to_json(),
+            r##"{"year":2022,"day":1,"part":2,"answer":"#.\n\"a\"\t\u001b","elapsed_ns":0,"status":"solved"}"##
+        );
+
+        assert_eq!(
+            result(None, Duration::from_nanos(7)).to_json(),
+            r#"{"year":2022,"day":1,"part":2,"answer":null,"elapsed_ns":7,"status":"unsolved"}"#
+        );
+    }
+
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_json_record_answers() {
+        let mut correct = result(Some("45000\n"), Duration::ZERO);
+        correct.expected = Some("45000".into());
+        assert_eq!(correct.status(), Status::Correct);
+        assert_eq!(
+            correct.to_json(),
+            r#"{"year":2022,"day":1,"part":2,"answer":"45000\n","elapsed_ns":0,"status":"correct"}"#
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+        let mut wrong = result(Some("45001"), Duration::ZERO);
+        wrong.expected = Some("45000".into());
+        assert_eq!(wrong.status(), Status::Wrong);
+        assert_eq!(
+            wrong.to_json(),
+            r#"{"year":2022,"day":1,"part":2,"answer":"45001","elapsed_ns":0,"status":"wrong","expected":"45000"}"#
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+        let mut measured = result(Some("45000"), Duration::ZERO);
+        measured.memory = Some(MemoryStats {
+            peak_bytes: 2048,
+            allocations: 3,
+        });
+        assert_eq!(
+            measured.to_json(),
+            r#"{"year":2022,"day":1,"part":2,"answer":"45000","elapsed_ns":0,"status":"solved","memory":{"peak_bytes":2048,"allocations":3}}"#
         );
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+        let mut unsolved = result(None, Duration::ZERO);
+        unsolved.expected = Some("45000".into());
+        assert_eq!(unsolved.status(), Status::Unsolved);
+    }
+
+    #[test]
+    fn test_json_record_bench() {
+        let stats = BenchStats {
+            samples: 10,
+            min: Duration::from_nanos(90),
+            median: Duration::from_nanos(100),
+            mean: Duration::from_nanos(105),
+            stddev: Duration::from_nanos(8),
+        };
+        let mut result = result(Some("1"), stats.median);
+        result.bench = Some(stats);
+        result.baseline = Some(Duration::from_nanos(120));
+
+        assert_eq!(
+            result.to_json(),
+            r#"{"year":2022,"day":1,"part":2,"answer":"1","elapsed_ns":100,"status":"solved","bench":{"samples":10,"min_ns":90,"median_ns":100,"mean_ns":105,"stddev_ns":8,"baseline_median_ns":120}}"#
         );
     }
 }
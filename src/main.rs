@@ -2,45 +2,131 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::options::Options;
+use advent_of_code::runner::{self, NO_INPUT};
+use advent_of_code::{PartResult, Status, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::process;
+use std::time::Duration;
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    let json = Options::current().json;
+    let year = Options::current().year();
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+    if Options::current().input.is_some() {
+        eprintln!("--input selects the input of a single day, use it with `cargo solve`.");
+        process::exit(1);
+    }
 
+    let days: Vec<u8> = (1..=25)
+        .filter(|day| Options::current().runs_day(*day))
+        .collect();
+    let inputs: Vec<_> = days
+        .iter()
+        .map(|day| advent_of_code::read_selected_input(year, *day))
+        .collect();
+
+    // with `--parallel`, days are run up front and their results printed in order below.
+    let jobs = Options::current().jobs();
+    let mut checked = if jobs > 1 {
+        let runnable: Vec<_> = days
+            .iter()
+            .zip(&inputs)
+            .filter_map(|(day, input)| {
+                let solution = advent_of_code::find_day(year, *day)?;
+                Some((solution, input.as_deref().ok()?.into()))
+            })
+            .collect();
+        eprintln!(
+            "Running {} days on {} threads...",
+            runnable.len(),
+            jobs.min(runnable.len())
+        );
+        runner::run_parallel(year, &runnable, jobs).into_iter()
+    } else {
+        Vec::new().into_iter()
+    };
+
+    let mut results: Vec<PartResult> = Vec::new();
+    let mut rows = Vec::new();
+
+    for (day, input) in days.into_iter().zip(inputs) {
+        if !json {
             println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
             println!("----------");
+        }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+        // labels of both parts in the summary table, `None` for parts that were not selected.
+        let selected =
+            |label| [1, 2].map(|part| Options::current().runs_part(part).then_some(label));
+        let labels = match (advent_of_code::find_day(year, day), input) {
+            (Some(solution), Ok(input)) => {
+                let day_results = match checked.next() {
+                    Some(day_results) => {
+                        for result in &day_results {
+                            advent_of_code::print_part_header(result.part);
+                            result.report();
+                        }
+                        day_results
+                    }
+                    None => runner::run_day(year, solution, &input),
+                };
+                let mut labels = [None; 2];
+                for result in &day_results {
+                    labels[result.part as usize - 1] = Some(result.status().as_str());
                 }
-            );
-
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+                results.extend(day_results);
+                labels
             }
-        })
-        .sum();
+            (Some(_), Err(e)) => {
+                if !json {
+                    print!("No input: {}.", e);
+                    if Options::current().puzzle_input() {
+                        print!(" Type `cargo download {}` to download it.", day);
+                    }
+                    println!();
+                }
+                selected(NO_INPUT)
+            }
+            (None, _) => {
+                if !json {
+                    println!("Not solved.");
+                }
+                selected(Status::Unsolved.as_str())
+            }
+        };
+        rows.push((day, labels));
+    }
+
+    let total: Duration = results.iter().map(|result| result.elapsed).sum();
+    let failed = results.iter().any(|result| {
+        matches!(
+            result.status(),
+            Status::Wrong | Status::Panicked | Status::TimedOut
+        )
+    });
+
+    if !json {
+        println!("----------");
+        print!("{}", runner::summary_table(&rows));
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+        // see `runner::run_parallel` for when the parallel timings are kept.
+        let timed_out = results
+            .iter()
+            .any(|result| result.status() == Status::TimedOut);
+        if jobs > 1 && (Options::current().skip_timing || timed_out) {
+            println!("Timings were measured while other days were running.");
+        }
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if failed {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,176 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+
+/// Heap usage of a single run of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Most bytes allocated at once, on top of what was allocated before the run.
+    pub peak_bytes: usize,
+    /// Number of allocations and reallocations.
+    pub allocations: usize,
+}
+
+impl fmt::Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+        let mut peak = self.peak_bytes as f64;
+        let mut unit = 0;
+        while peak >= 1024.0 && unit < UNITS.len() - 1 {
+            peak /= 1024.0;
+            unit += 1;
+        }
+        if unit == 0 {
+            write!(f, "peak: {}B", self.peak_bytes)?;
+        } else {
+            write!(f, "peak: {:.1}{}", peak, UNITS[unit])?;
+        }
+        write!(f, ", allocs: {}", self.allocations)
+    }
+}
+
+/// Returns `true` if the crate was built with the `memory` feature, which counts allocations.
+pub fn enabled() -> bool {
+    cfg!(feature = "memory")
+}
+
+/// Runs `f` and returns its heap usage, if the `memory` feature is enabled.
+/// Only allocations on the current thread are counted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    #[cfg(feature = "memory")]
+    {
+        let start = counting::snapshot();
+        counting::reset_peak();
+        let result = f();
+        let end = counting::snapshot();
+        let stats = MemoryStats {
+            peak_bytes: end.peak.saturating_sub(start.current),
+            allocations: end.allocations - start.allocations,
+        };
+        (result, Some(stats))
+    }
+
+    #[cfg(not(feature = "memory"))]
+    {
+        (f(), None)
+    }
+}
+
+/// A global allocator that keeps per-thread counts of the allocated bytes.
+#[cfg(feature = "memory")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    thread_local! {
+        static CURRENT: Cell<usize> = const { Cell::new(0) };
+        static PEAK: Cell<usize> = const { Cell::new(0) };
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    pub struct Snapshot {
+        pub current: usize,
+        pub peak: usize,
+        pub allocations: usize,
+    }
+
+    pub fn snapshot() -> Snapshot {
+        Snapshot {
+            current: CURRENT.with(Cell::get),
+            peak: PEAK.with(Cell::get),
+            allocations: ALLOCATIONS.with(Cell::get),
+        }
+    }
+
+    pub fn reset_peak() {
+        PEAK.with(|peak| peak.set(CURRENT.with(Cell::get)));
+    }
+
+    // the counters are not available while a thread shuts down, those allocations are skipped.
+    fn grow(size: usize, count: bool) {
+        let _ = CURRENT.try_with(|current| {
+            let size = current.get() + size;
+            current.set(size);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(size)));
+        });
+        if count {
+            let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        }
+    }
+
+    // memory freed on another thread than it was allocated on can take the count below zero.
+    fn shrink(size: usize) {
+        let _ = CURRENT.try_with(|current| current.set(current.get().saturating_sub(size)));
+    }
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                grow(layout.size(), true);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                grow(layout.size(), true);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            shrink(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                shrink(layout.size());
+                grow(new_size, true);
+            }
+            new_ptr
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let stats = |peak_bytes| MemoryStats {
+            peak_bytes,
+            allocations: 3,
+        };
+        assert_eq!(stats(512).to_string(), "peak: 512B, allocs: 3");
+        assert_eq!(stats(1536).to_string(), "peak: 1.5KiB, allocs: 3");
+        assert_eq!(stats(5 << 20).to_string(), "peak: 5.0MiB, allocs: 3");
+    }
+
+    #[test]
+    fn test_measure() {
+        let (sum, stats) = measure(|| {
+            let numbers: Vec<u64> = (0..1000).collect();
+            numbers.iter().sum::<u64>()
+        });
+        assert_eq!(sum, 499500);
+        match stats {
+            Some(stats) => {
+                assert!(stats.peak_bytes >= 8000);
+                assert!(stats.allocations >= 1);
+            }
+            None => assert!(!enabled()),
+        }
+    }
+}
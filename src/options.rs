@@ -0,0 +1,230 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Duration;
+
+use crate::client;
+
+/// Set this env var (to anything but `0`) to make the runners print JSON records instead of text.
+pub const JSON_ENV_VAR: &str = "AOC_JSON";
+
+/// Time a part may take in the all-days runner before it is reported as timed out.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Flags shared by the solution binaries and the all-days runner.
+#[derive(Debug, Default)]
+pub struct Options {
+    /// Year to run, instead of the default year (`--year <year>`).
+    pub year: Option<u16>,
+    /// Print one JSON record per part instead of formatted output (`--json`).
+    pub json: bool,
+    /// Run each part repeatedly and report timing statistics (`--bench`).
+    pub bench: bool,
+    /// Compare bench results against the medians stored in this file (`--baseline <file>`).
+    pub baseline: Option<PathBuf>,
+    /// Store bench medians in this file (`--save-baseline <file>`).
+    pub save_baseline: Option<PathBuf>,
+    /// Read the input from this file instead of the puzzle input, `-` for stdin (`--input <path>`).
+    pub input: Option<PathBuf>,
+    /// Read the input from the examples folder instead of the puzzle input (`--example`).
+    pub example: bool,
+    /// Only run this part (`--part <1|2>`).
+    pub part: Option<u8>,
+    /// Only run these days in the all-days runner (`--days <ranges>`, e.g. `1-5,7`).
+    pub days: Option<Vec<u8>>,
+    /// Per-part timeout of the all-days runner, `0` disables it (`--timeout <seconds>`).
+    pub timeout: Option<Duration>,
+    /// Run days of the all-days runner concurrently (`--parallel`).
+    pub parallel: bool,
+    /// Number of days run at once with `--parallel`, defaults to the number of CPUs (`--jobs <n>`).
+    pub jobs: Option<usize>,
+    /// Skip the sequential timing pass after a `--parallel` run (`--skip-timing`).
+    pub skip_timing: bool,
+    /// Run the selected parts this many times without printing, for profilers (`--profile <n>`).
+    pub profile: Option<usize>,
+    /// Record answers of parts without an accepted answer in `src/yYYYY/answers/` (`--save-answers`).
+    pub save_answers: bool,
+}
+
+impl Options {
+    /// Parses known flags from `args`. Unknown arguments are left for the caller.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let baseline: Option<PathBuf> = args.opt_value_from_str("--baseline")?;
+        let save_baseline: Option<PathBuf> = args.opt_value_from_str("--save-baseline")?;
+        let jobs: Option<usize> = args.opt_value_from_fn(["-j", "--jobs"], parse_count)?;
+
+        Ok(Options {
+            year: args.opt_value_from_str(["-y", "--year"])?,
+            json: args.contains("--json")
+                || env::var(JSON_ENV_VAR).is_ok_and(|val| !val.is_empty() && val != "0"),
+            bench: args.contains("--bench") || baseline.is_some() || save_baseline.is_some(),
+            baseline,
+            save_baseline,
+            input: args.opt_value_from_str(["-i", "--input"])?,
+            example: args.contains(["-e", "--example"]),
+            part: args.opt_value_from_fn("--part", parse_part)?,
+            days: args.opt_value_from_fn("--days", parse_days)?,
+            timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+            parallel: args.contains("--parallel") || jobs.is_some(),
+            jobs,
+            skip_timing: args.contains("--skip-timing"),
+            profile: args.opt_value_from_fn("--profile", parse_count)?,
+            save_answers: args.contains("--save-answers"),
+        })
+    }
+
+    /// Returns the selected year, falling back to `AOC_YEAR` or the most recent event.
+    pub fn year(&self) -> u16 {
+        self.year.unwrap_or_else(client::default_year)
+    }
+
+    /// Returns `true` unless another input was selected with `--input` or `--example`.
+    pub fn puzzle_input(&self) -> bool {
+        self.input.is_none() && !self.example
+    }
+
+    /// Returns `true` if `part` was selected, i.e. it was passed to `--part` or no part was.
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// Returns `true` if `day` was selected with `--days` or no days were.
+    pub fn runs_day(&self, day: u8) -> bool {
+        self.days.as_ref().is_none_or(|days| days.contains(&day))
+    }
+
+    /// Returns the timeout of a part in the all-days runner, `None` if it was disabled.
+    pub fn timeout(&self) -> Option<Duration> {
+        match self.timeout {
+            None => Some(DEFAULT_TIMEOUT),
+            Some(timeout) if timeout.is_zero() => None,
+            Some(timeout) => Some(timeout),
+        }
+    }
+
+    /// Returns the number of days run at once, `1` unless `--parallel` was passed.
+    pub fn jobs(&self) -> usize {
+        match (self.parallel, self.jobs) {
+            (false, _) => 1,
+            (true, Some(jobs)) => jobs,
+            (true, None) => thread::available_parallelism().map_or(1, usize::from),
+        }
+    }
+
+    /// Returns the options of the current process, parsed from the command line on first access.
+    pub fn current() -> &'static Options {
+        static OPTIONS: OnceLock<Options> = OnceLock::new();
+        OPTIONS.get_or_init(
+            || match Options::parse(&mut pico_args::Arguments::from_env()) {
+                Ok(options) => options,
+                Err(e) => {
+                    eprintln!("Failed to process arguments: {}", e);
+                    process::exit(1);
+                }
+            },
+        )
+    }
+}
+
+fn parse_part(part: &str) -> Result<u8, String> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err("part must be 1 or 2".into()),
+    }
+}
+
+fn parse_count(count: &str) -> Result<usize, String> {
+    match count.parse() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(format!("\"{}\" is not a positive number", count)),
+    }
+}
+
+/// Parses a timeout in (fractional) seconds.
+fn parse_timeout(secs: &str) -> Result<Duration, String> {
+    secs.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("invalid timeout \"{}\"", secs))
+}
+
+/// Parses a comma-separated list of days and ranges like `1-5,7`.
+fn parse_days(days: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day \"{}\"", day.trim())),
+    };
+
+    let mut selected = Vec::new();
+    for range in days.split(',') {
+        let (start, end) = match range.split_once('-') {
+            Some((start, end)) => (parse_day(start)?, parse_day(end)?),
+            None => (parse_day(range)?, parse_day(range)?),
+        };
+        if start > end {
+            return Err(format!("invalid range \"{}\"", range.trim()));
+        }
+        selected.extend(start..=end);
+    }
+    selected.sort_unstable();
+    selected.dedup();
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+        assert_eq!(parse_days("1-3,7, 10-11"), Ok(vec![1, 2, 3, 7, 10, 11]));
+        assert_eq!(parse_days("3-4,1-4"), Ok(vec![1, 2, 3, 4]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("1,,2").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(parse_part("1"), Ok(1));
+        assert_eq!(parse_part("2"), Ok(2));
+        assert!(parse_part("3").is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("10"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_timeout("0.5"), Ok(Duration::from_millis(500)));
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("soon").is_err());
+
+        let mut options = Options::default();
+        assert_eq!(options.timeout(), Some(DEFAULT_TIMEOUT));
+        options.timeout = Some(Duration::ZERO);
+        assert_eq!(options.timeout(), None);
+    }
+
+    #[test]
+    fn test_jobs() {
+        assert_eq!(parse_count("4"), Ok(4));
+        assert!(parse_count("0").is_err());
+        assert!(parse_count("many").is_err());
+
+        let mut options = Options {
+            jobs: Some(4),
+            ..Options::default()
+        };
+        assert_eq!(options.jobs(), 1);
+        options.parallel = true;
+        assert_eq!(options.jobs(), 4);
+    }
+}
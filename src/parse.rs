@@ -0,0 +1,350 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::cell::Cell;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::helpers::Grid;
+
+use nom::{
+    character::complete::{char, digit1, line_ending, one_of, space0},
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{pair, tuple},
+    Finish, IResult, Parser,
+};
+
+thread_local! {
+    /// Furthest failure of an item of the list parsers, as the length of the input left at it
+    /// and what went wrong. [`parse_all`] reports it if it lies beyond where parsing stopped.
+    static FURTHEST_FAILURE: Cell<Option<(usize, ErrorKind)>> = const { Cell::new(None) };
+}
+
+/// Parses an unsigned integer, e.g. `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Parses an integer with an optional sign, e.g. `-42` or `+42`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(
+        recognize(pair(opt(one_of("+-")), digit1)),
+        |number: &str| number.strip_prefix('+').unwrap_or(number).parse(),
+    )(input)
+}
+
+/// Extracts all integers from `line`, ignoring the text around them.
+/// A `-` directly in front of a number is its sign, unless it follows a digit like in the
+/// range `2-4`. Numbers that do not fit into `T` are skipped.
+///
+/// ```
+/// use advent_of_code::parse::integers;
+/// let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+/// assert_eq!(integers::<i32>(line), [2, -18, -2, 15]);
+/// ```
+pub fn integers<T: FromStr>(line: &str) -> Vec<T> {
+    let bytes = line.as_bytes();
+    let mut numbers = Vec::new();
+    let mut end = 0;
+    while end < bytes.len() {
+        if !bytes[end].is_ascii_digit() {
+            end += 1;
+            continue;
+        }
+        let mut start = end;
+        while end < bytes.len() && bytes[end].is_ascii_digit() {
+            end += 1;
+        }
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_digit())
+        {
+            start -= 1;
+        }
+        if let Ok(number) = line[start..end].parse() {
+            numbers.push(number);
+        }
+    }
+    numbers
+}
+
+/// Parses a list of `item`s separated by commas, optionally followed by spaces.
+pub fn comma_list<'a, T>(
+    item: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    list(pair(char(','), space0), item)
+}
+
+/// Parses one `item` per line.
+pub fn lines<'a, T>(
+    item: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    list(line_ending, item)
+}
+
+/// Parses `block`s separated by blank lines, e.g. groups of lines.
+pub fn blocks<'a, T>(
+    block: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    list(tuple((line_ending, line_ending)), block)
+}
+
+/// Like `separated_list1`, but remembers where an item failed. The list ends there without
+/// an error, which would otherwise be lost.
+fn list<'a, T, S>(
+    separator: impl Parser<&'a str, S, Error<&'a str>>,
+    mut item: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    let item = move |input: &'a str| {
+        item.parse(input).inspect_err(|e| {
+            if let nom::Err::Error(error) = e {
+                FURTHEST_FAILURE.with(|furthest| match furthest.get() {
+                    Some((rest, _)) if rest <= error.input.len() => {}
+                    _ => furthest.set(Some((error.input.len(), error.code))),
+                });
+            }
+        })
+    };
+    separated_list1(separator, item)
+}
+
+/// An error in the puzzle input, with the line it happened in and what was expected there.
+///
+/// Solutions that cannot continue with a broken input pass it to [`ParseError::raise`]
+/// instead of panicking with a message, the runner then shows it like this:
+///
+/// ```text
+/// invalid input: line 2, column 6: expected a number
+///   |
+/// 2 | addx x
+///   |      ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The line the error is in.
+    pub snippet: String,
+    /// Description of what should have been there, e.g. "a number".
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error at byte `offset` of `input`.
+    pub fn new(input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        ParseError {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error at the start of `rest`, which must be a slice of `input`, e.g. a line
+    /// from `input.lines()` or the remaining input of a parser.
+    ///
+    /// # Panics
+    /// If `rest` is not part of `input`.
+    pub fn at(input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let offset = (rest.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + rest.len() <= input.len())
+            .expect("rest is not a slice of input");
+        ParseError::new(input, offset, expected)
+    }
+
+    /// Panics with this error, which is shown as invalid input instead of the usual panic
+    /// message, in any binary and in tests. Meant for `result.unwrap_or_else(ParseError::raise)`.
+    pub fn raise<T>(self) -> T {
+        crate::runner::install_panic_hook();
+        std::panic::panic_any(self)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{} | {:>width$}", gutter, "^", width = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` on the whole `input`. Trailing newlines are ignored, anything else that is
+/// left over is an error. If an item of [`comma_list`], [`lines`] or [`blocks`] failed further
+/// into the input, e.g. a broken number in the last line, that failure is reported instead.
+///
+/// ```
+/// use advent_of_code::parse::{comma_list, lines, parse_all, unsigned};
+/// assert_eq!(parse_all(lines(comma_list(unsigned::<u8>)), "1,2\n3\n"), Ok(vec![vec![1, 2], vec![3]]));
+///
+/// let error = parse_all(lines(comma_list(unsigned::<u8>)), "1,2\n3,x").unwrap_err();
+/// assert_eq!((error.line, error.column), (2, 3));
+/// assert_eq!(error.expected, "a number");
+/// ```
+pub fn parse_all<'a, T>(
+    parser: impl Parser<&'a str, T, Error<&'a str>>,
+    input: &'a str,
+) -> Result<T, ParseError> {
+    let trimmed = input.trim_end_matches(['\r', '\n']);
+    FURTHEST_FAILURE.with(|furthest| furthest.set(None));
+    let result = all_consuming(parser)(trimmed).finish();
+    let furthest = FURTHEST_FAILURE.with(|furthest| furthest.take());
+    result.map(|(_, value)| value).map_err(|e| match furthest {
+        Some((rest, code)) if rest < e.input.len() => {
+            ParseError::new(input, trimmed.len() - rest, expectation(code))
+        }
+        _ => ParseError::at(input, e.input, expectation(e.code)),
+    })
+}
+
+/// Parses a character map into a [`Grid`], one row per line, converting each character with
+/// `cell`. A character that `cell` rejects or a row of a different length is an error, with
+/// `expected` describing the valid characters.
+///
+/// ```
+/// use advent_of_code::parse::grid;
+/// let digits = |input| grid(input, "a digit", |c| c.to_digit(10));
+/// assert_eq!(digits("12\n34").unwrap().row(1), [3, 4]);
+/// assert_eq!(digits("12\n3x").unwrap_err().column, 2);
+/// ```
+pub fn grid<T>(
+    input: &str,
+    expected: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>, ParseError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for line in input.lines() {
+        let row = line
+            .char_indices()
+            .map(|(i, c)| cell(c).ok_or_else(|| ParseError::at(input, &line[i..], expected)))
+            .collect::<Result<Vec<T>, _>>()?;
+        if let Some(width) = rows
+            .first()
+            .map(Vec::len)
+            .filter(|width| *width != row.len())
+        {
+            let end = line
+                .char_indices()
+                .nth(width)
+                .map_or(line.len(), |(i, _)| i);
+            let expected = format!("a row of {} cells", width);
+            return Err(ParseError::at(input, &line[end..], expected));
+        }
+        rows.push(row);
+    }
+    Ok(Grid::from_rows(rows).expect("rows have the same length"))
+}
+
+fn expectation(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Digit | ErrorKind::MapRes => "a number",
+        ErrorKind::Char | ErrorKind::OneOf => "a specific character",
+        ErrorKind::Tag => "a specific text",
+        ErrorKind::CrLf => "a line break",
+        ErrorKind::Eof => "the end of the input",
+        ErrorKind::Alpha | ErrorKind::AlphaNumeric => "a letter",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        _ => "something else",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(unsigned::<u32>("42 rest"), Ok((" rest", 42)));
+        assert!(unsigned::<u32>("").is_err());
+        assert!(unsigned::<u8>("256").is_err());
+        assert_eq!(signed::<i32>("-42"), Ok(("", -42)));
+        assert_eq!(signed::<i32>("+42"), Ok(("", 42)));
+        assert!(signed::<i32>("-").is_err());
+
+        assert_eq!(integers::<i64>("move 3 from 12 to -1"), [3, 12, -1]);
+        assert_eq!(integers::<i64>("2-4,6-8"), [2, 4, 6, 8]);
+        assert_eq!(integers::<i64>("-5 to x=-3"), [-5, -3]);
+        assert_eq!(integers::<u8>("-1, 300, 4"), [4]);
+        assert!(integers::<u8>("no numbers").is_empty());
+    }
+
+    #[test]
+    fn test_lists() {
+        let input = "1, 2,3\n4\n\n5\n6";
+        assert_eq!(
+            parse_all(blocks(lines(comma_list(unsigned::<u32>))), input),
+            Ok(vec![vec![vec![1, 2, 3], vec![4]], vec![vec![5], vec![6]]])
+        );
+    }
+
+    #[test]
+    fn test_parse_all_errors() {
+        let parser = || lines(comma_list(signed::<i32>));
+        let error = |input| {
+            let e = parse_all(parser(), input).unwrap_err();
+            (e.line, e.column, e.expected)
+        };
+        assert_eq!(
+            parse_all(parser(), "1,2\r\n3\r\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(error("1,2\n3;4\n"), (2, 2, "the end of the input".into()));
+        assert_eq!(error("1,2\n3,x"), (2, 3, "a number".into()));
+        assert_eq!(error("1,2\n\n3"), (2, 1, "a number".into()));
+        assert_eq!(error("x"), (1, 1, "a number".into()));
+    }
+
+    #[test]
+    fn test_grid() {
+        let heights = |input| grid(input, "a height", |c| c.is_ascii_lowercase().then_some(c));
+        assert_eq!(heights("ab\ncd").unwrap().to_string(), "ab\ncd");
+
+        let error = heights("ab\ncD").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "a height");
+
+        let error = heights("ab\nc\nde").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "a row of 2 cells");
+        let error = heights("ab\ncde").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "noop\r\naddx x\r\nnoop";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::at(input, &line[5..], "a number");
+        assert_eq!(error.snippet, "addx x");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: expected a number\n  |\n2 | addx x\n  |      ^"
+        );
+
+        let error = ParseError::new("ab\nc", 4, "more");
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected more\n  |\n2 | c\n  |  ^"
+        );
+    }
+}
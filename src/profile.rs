@@ -0,0 +1,157 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::cell::RefCell;
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::Day;
+
+thread_local! {
+    /// Time spent in each phase, only recorded while a part is profiled.
+    static PHASES: RefCell<Option<Vec<(&'static str, Duration)>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` as a named phase of a solution, e.g. `"parse"`.
+///
+/// When the part runs with `--profile`, the time spent in each phase is reported separately.
+/// Otherwise, this only calls `f`. Phases should not be nested.
+pub fn phase<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    if PHASES.with(|phases| phases.borrow().is_none()) {
+        return f();
+    }
+
+    let timer = Instant::now();
+    let result = f();
+    let elapsed = timer.elapsed();
+
+    PHASES.with(|phases| {
+        if let Some(phases) = phases.borrow_mut().as_mut() {
+            match phases.iter_mut().find(|(phase, _)| *phase == name) {
+                Some((_, total)) => *total += elapsed,
+                None => phases.push((name, elapsed)),
+            }
+        }
+    });
+    result
+}
+
+/// Total time of repeated runs of a part, along with the time spent in its phases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub runs: usize,
+    pub elapsed: Duration,
+    /// Phases in the order they were first entered, see [`phase`].
+    pub phases: Vec<(&'static str, Duration)>,
+}
+
+impl Profile {
+    fn per_run(&self, elapsed: Duration) -> Duration {
+        elapsed.div_f64(self.runs as f64)
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} runs in {:.2?} ({:.2?} per run)",
+            self.runs,
+            self.elapsed,
+            self.per_run(self.elapsed)
+        )?;
+
+        if self.phases.is_empty() {
+            return Ok(());
+        }
+
+        let attributed: Duration = self.phases.iter().map(|(_, elapsed)| *elapsed).sum();
+        let other = self.elapsed.saturating_sub(attributed);
+        let width = self
+            .phases
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        for (name, elapsed) in self.phases.iter().chain([&("other", other)]) {
+            write!(
+                f,
+                "\n  {:width$}  {:>10} per run ({:.1}%)",
+                name,
+                format!("{:.2?}", self.per_run(*elapsed)),
+                elapsed.as_secs_f64() / self.elapsed.as_secs_f64() * 100.0,
+                width = width.max("other".len())
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs `solver` on `input` `runs` times without printing anything, recording its phases.
+pub fn profile<R>(solver: impl Fn(&str) -> R, input: &str, runs: usize) -> Profile {
+    PHASES.with(|phases| phases.replace(Some(Vec::new())));
+
+    let timer = Instant::now();
+    for _ in 0..runs {
+        black_box(solver(black_box(input)));
+    }
+    let elapsed = timer.elapsed();
+
+    Profile {
+        runs,
+        elapsed,
+        phases: PHASES.with(|phases| phases.take()).unwrap_or_default(),
+    }
+}
+
+/// Profiles the parts of `day` selected with `--part`.
+pub fn profile_day(day: &Day, input: &str, runs: usize) -> Vec<(u8, Profile)> {
+    day.selected_parts()
+        .map(|(part, solver)| (part, profile(solver, input, runs)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solver(input: &str) -> Option<usize> {
+        let numbers: Vec<usize> = phase("parse", || {
+            input.split(',').map(|n| n.parse().unwrap()).collect()
+        });
+        phase("sum", || Some(numbers.iter().sum()))
+    }
+
+    #[test]
+    fn test_profile() {
+        let profile = profile(solver, "1,2,3", 10);
+        assert_eq!(profile.runs, 10);
+        let phases: Vec<&str> = profile.phases.iter().map(|(name, _)| *name).collect();
+        assert_eq!(phases, ["parse", "sum"]);
+        assert!(profile
+            .phases
+            .iter()
+            .all(|(_, elapsed)| *elapsed <= profile.elapsed));
+
+        // phases are only recorded while profiling.
+        assert_eq!(solver("4"), Some(4));
+        assert!(PHASES.with(|phases| phases.borrow().is_none()));
+    }
+
+    #[test]
+    fn test_display() {
+        let profile = Profile {
+            runs: 4,
+            elapsed: Duration::from_micros(40),
+            phases: vec![("parse", Duration::from_micros(10))],
+        };
+        assert_eq!(
+            profile.to_string(),
+            "4 runs in 40.00µs (10.00µs per run)\n  \
+             parse      2.50µs per run (25.0%)\n  \
+             other      7.50µs per run (75.0%)"
+        );
+    }
+}
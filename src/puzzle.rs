@@ -0,0 +1,300 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use regex::Regex;
+
+use crate::client::BASE_URL;
+
+/// Heading of the second article, present once part one is solved.
+pub const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Splits HTML into tags and text. Comments and the like are skipped.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(start) => rest.split_at(start),
+            None => (rest, ""),
+        };
+        if !text.is_empty() {
+            tokens.push(Token::Text(text));
+        }
+        let end = match tag.find('>') {
+            Some(end) => end,
+            None => break,
+        };
+        let inner = &tag[1..end];
+        rest = &tag[end + 1..];
+
+        if let Some(name) = inner.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else if !inner.starts_with('!') {
+            let inner = inner.trim_end_matches('/');
+            let (name, attrs) = inner.split_once(' ').unwrap_or((inner, ""));
+            tokens.push(Token::Open(name, attrs));
+        }
+    }
+    tokens
+}
+
+fn attr(attrs: &str, name: &str) -> Option<String> {
+    let re = Regex::new(&format!(r#"\b{}="([^"]*)""#, name)).unwrap();
+    re.captures(attrs)
+        .map(|captures| decode_entities(&captures[1]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let re = Regex::new(r"&(#x[0-9a-fA-F]+|#[0-9]+|[a-z]+);").unwrap();
+    re.replace_all(text, |captures: &regex::Captures| {
+        let entity = &captures[1];
+        let decoded = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        decoded.map_or_else(|| captures[0].to_string(), String::from)
+    })
+    .into_owned()
+}
+
+/// Returns the contents of the `<article>` elements of a puzzle page, one per unlocked part.
+pub fn articles(html: &str) -> Vec<&str> {
+    let re = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    re.captures_iter(html)
+        .map(|captures| captures.get(1).unwrap().as_str())
+        .collect()
+}
+
+/// Converts the puzzle description of a puzzle page to Markdown.
+/// Only handles the handful of elements used in puzzle descriptions.
+pub fn to_markdown(html: &str) -> String {
+    articles(html)
+        .iter()
+        .map(|article| article_to_markdown(article))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn article_to_markdown(html: &str) -> String {
+    // `<code><em>` can't be expressed in Markdown, emphasize the whole code span instead.
+    let html = html
+        .replace("<code><em>", "<em><code>")
+        .replace("</em></code>", "</code></em>");
+
+    let mut markdown = String::new();
+    let mut pre = false;
+    let mut links = Vec::new();
+    let whitespace = Regex::new(r"\s+").unwrap();
+
+    for token in tokenize(&html) {
+        match token {
+            Token::Text(text) if pre => markdown.push_str(&decode_entities(text)),
+            Token::Text(text) => {
+                let text = decode_entities(&whitespace.replace_all(text, " "));
+                if markdown.is_empty() || markdown.ends_with(['\n', ' ']) {
+                    markdown.push_str(text.trim_start());
+                } else {
+                    markdown.push_str(&text);
+                }
+            }
+            Token::Open("pre", _) => {
+                pre = true;
+                markdown.push_str("```\n");
+            }
+            Token::Close("pre") => {
+                pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            _ if pre => {}
+            Token::Open("h2", _) => markdown.push_str("## "),
+            Token::Close("h2") | Token::Close("p") | Token::Close("ul") => {
+                end_line(&mut markdown);
+                markdown.push('\n');
+            }
+            Token::Open("li", _) => markdown.push_str("- "),
+            Token::Close("li") => end_line(&mut markdown),
+            Token::Open("em", _) | Token::Close("em") => markdown.push('*'),
+            Token::Open("code", _) | Token::Close("code") => markdown.push('`'),
+            Token::Open("a", attrs) => {
+                let href = attr(attrs, "href").unwrap_or_default();
+                links.push(if href.starts_with('/') {
+                    format!("{}{}", BASE_URL, href)
+                } else {
+                    href
+                });
+                markdown.push('[');
+            }
+            Token::Close("a") => {
+                markdown.push_str(&format!("]({})", links.pop().unwrap_or_default()));
+            }
+            _ => {}
+        }
+    }
+
+    markdown.trim().to_string() + "\n"
+}
+
+fn end_line(markdown: &mut String) {
+    markdown.truncate(markdown.trim_end_matches(' ').len());
+    if !markdown.ends_with('\n') {
+        markdown.push('\n');
+    }
+}
+
+/// Returns the first `<pre><code>` block of a puzzle page, which usually holds the example input.
+pub fn first_example(html: &str) -> Option<String> {
+    let re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let code = re.captures(html)?;
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    Some(decode_entities(&tags.replace_all(&code[1], "")))
+}
+
+/// Example input of a puzzle and the answers given for it.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// Reads the example from a puzzle page or its Markdown conversion.
+/// The example is the first code block, the answer of a part the last emphasized code span.
+pub fn example(puzzle: &str) -> Option<Example> {
+    let markdown = if puzzle.contains("<article") {
+        to_markdown(puzzle)
+    } else {
+        puzzle.to_string()
+    };
+    let (part_one, part_two) = match markdown.split_once(PART_TWO_HEADING) {
+        Some((part_one, part_two)) => (part_one, Some(part_two)),
+        None => (markdown.as_str(), None),
+    };
+
+    let code_block = Regex::new(r"(?s)```\n(.*?)```").unwrap();
+    let answer = Regex::new(r"\*`([^`]+)`\*").unwrap();
+    let last_answer = |text: &str| {
+        answer
+            .captures_iter(text)
+            .last()
+            .map(|captures| captures[1].to_string())
+    };
+
+    Some(Example {
+        input: code_block.captures(part_one)?[1].to_string(),
+        part_one: last_answer(part_one),
+        part_two: part_two.and_then(last_answer),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <a href="/2022/about" title="tooltip">Calories</a> contained by the various meals.</p>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+<em>3000</em>
+</code></pre>
+<ul>
+<li>The first Elf is carrying food with <code>1000</code> and <code>2000</code> Calories.</li>
+<li>Compare &lt;a&gt; &amp; &quot;b&quot;</li>
+</ul>
+<p>In the example above, this is <em><code>24000</code></em> or <code><em>24000</em></code> (carried by the <em>fourth</em> Elf).</p>
+</article>
+<p>Your puzzle answer was <code>68292</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>By the time you calculate the answer, they've already realized <span title="x">that</span> it's not enough.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_articles() {
+        assert_eq!(articles(PAGE).len(), 2);
+        assert!(articles(PAGE)[1].contains(PART_TWO_HEADING));
+        assert!(articles("<main><p>Not unlocked</p></main>").is_empty());
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE),
+            "## --- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of [Calories](https://adventofcode.com/2022/about) contained by the various meals.
+
+For example:
+
+```
+1000
+2000
+
+3000
+```
+
+- The first Elf is carrying food with `1000` and `2000` Calories.
+- Compare <a> & \"b\"
+
+In the example above, this is *`24000`* or *`24000`* (carried by the *fourth* Elf).
+
+## --- Part Two ---
+
+By the time you calculate the answer, they've already realized that it's not enough.
+"
+        );
+    }
+
+    #[test]
+    fn test_first_example() {
+        assert_eq!(
+            first_example(PAGE),
+            Some("1000\n2000\n\n3000\n".to_string())
+        );
+        assert_eq!(first_example("<p>No example</p>"), None);
+    }
+
+    #[test]
+    fn test_example() {
+        let expected = Example {
+            input: "1000\n2000\n\n3000\n".to_string(),
+            part_one: Some("24000".to_string()),
+            part_two: None,
+        };
+        assert_eq!(example(PAGE), Some(expected));
+
+        let markdown = "## --- Day 2 ---\n\n```\nA Y\n```\n\nScore *`8`* or *`15`*.\n\n## --- Part Two ---\n\nScore *`12`*.\n";
+        let expected = Example {
+            input: "A Y\n".to_string(),
+            part_one: Some("15".to_string()),
+            part_two: Some("12".to_string()),
+        };
+        assert_eq!(example(markdown), Some(expected));
+
+        assert_eq!(example("No code here."), None);
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("&lt;&#62;&#x26;&unknown;"), "<>&&unknown;");
+    }
+}
@@ -0,0 +1,272 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::root_dir;
+
+/// Encloses the section of the README that `cargo time` rewrites.
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+/// Timings of the solved parts of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.part_one.unwrap_or_default() + self.part_two.unwrap_or_default()
+    }
+}
+
+pub fn readme_path() -> PathBuf {
+    root_dir().join("README.md")
+}
+
+/// Formats a Markdown table of the `timings` of `year`, linking each day to its solution.
+pub fn timing_table(year: u16, timings: &[Timing]) -> String {
+    let format = |time: Option<Duration>| match time {
+        Some(time) => format!("`{:.1?}`", time),
+        None => "-".to_string(),
+    };
+
+    let mut table = format!("### {}\n\n", year);
+    table += "| Day | Part 1 | Part 2 | Total |\n";
+    table += "| :---: | ---: | ---: | ---: |\n";
+    for timing in timings {
+        writeln!(
+            table,
+            "| [Day {}](./src/y{}/day{:02}.rs) | {} | {} | {} |",
+            timing.day,
+            year,
+            timing.day,
+            format(timing.part_one),
+            format(timing.part_two),
+            format(Some(timing.total()))
+        )
+        .unwrap();
+    }
+
+    let total: Duration = timings.iter().map(Timing::total).sum();
+    write!(
+        table,
+        "\n**Total: {:.2}ms**",
+        total.as_secs_f64() * 1000_f64
+    )
+    .unwrap();
+    table
+}
+
+/// Formats the timing tables of all years, the latest year first.
+pub fn timing_section(tables: &BTreeMap<u16, Vec<Timing>>) -> String {
+    let tables: Vec<String> = tables
+        .iter()
+        .rev()
+        .map(|(year, timings)| timing_table(*year, timings))
+        .collect();
+    format!("## Benchmarks\n\n{}", tables.join("\n\n"))
+}
+
+/// Parses the rows of the tables written by [`timing_section`], grouped by the year of their
+/// solution link. Anything that is not a row is skipped. Timings are only as precise as the
+/// table.
+pub fn parse_timing_tables(section: &str) -> BTreeMap<u16, Vec<Timing>> {
+    let mut tables: BTreeMap<u16, Vec<Timing>> = BTreeMap::new();
+    let rows = section.lines().filter_map(|line| {
+        let cells: Vec<&str> = line
+            .trim()
+            .strip_prefix('|')?
+            .strip_suffix('|')?
+            .split('|')
+            .map(str::trim)
+            .collect();
+        let [day, part_one, part_two, _total] = cells[..] else {
+            return None;
+        };
+        let (day, link) = day.strip_prefix("[Day ")?.split_once("](./src/y")?;
+        let year = link.split_once("/day")?.0.parse().ok()?;
+        let timing = Timing {
+            day: day.parse().ok()?,
+            part_one: parse_duration(part_one),
+            part_two: parse_duration(part_two),
+        };
+        Some((year, timing))
+    });
+    for (year, timing) in rows {
+        tables.entry(year).or_default().push(timing);
+    }
+    tables
+}
+
+/// Parses a duration as formatted in the table, e.g. `` `2.5ms` ``.
+fn parse_duration(cell: &str) -> Option<Duration> {
+    let text = cell.strip_prefix('`')?.strip_suffix('`')?;
+    let unit_start = text.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = text.split_at(unit_start);
+    let nanos_per_unit = match unit {
+        "ns" => 1.0,
+        "µs" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+    Some(Duration::from_nanos(
+        (value.parse::<f64>().ok()? * nanos_per_unit).round() as u64,
+    ))
+}
+
+/// Combines the timings of a previous table with new ones, ordered by day. Parts that were
+/// not measured again, e.g. because of `--part`, keep their previous timing.
+pub fn merge_timings(previous: &[Timing], new: &[Timing]) -> Vec<Timing> {
+    let mut merged: BTreeMap<u8, Timing> = previous.iter().map(|t| (t.day, *t)).collect();
+    for timing in new {
+        merged
+            .entry(timing.day)
+            .and_modify(|merged| {
+                merged.part_one = timing.part_one.or(merged.part_one);
+                merged.part_two = timing.part_two.or(merged.part_two);
+            })
+            .or_insert(*timing);
+    }
+    merged.into_values().collect()
+}
+
+/// Returns the byte range of the text between the two [`MARKER`]s in `readme`.
+fn section_range(readme: &str) -> Result<Range<usize>, String> {
+    let start = readme
+        .find(MARKER)
+        .ok_or(format!("could not find \"{}\"", MARKER))?
+        + MARKER.len();
+    let end = start
+        + readme[start..]
+            .find(MARKER)
+            .ok_or(format!("could not find closing \"{}\"", MARKER))?;
+    Ok(start..end)
+}
+
+/// Replaces the text between the two [`MARKER`]s in `readme` with `section`.
+pub fn replace_section(readme: &str, section: &str) -> Result<String, String> {
+    let range = section_range(readme)?;
+    Ok(format!(
+        "{}\n{}\n{}",
+        &readme[..range.start],
+        section,
+        &readme[range.end..]
+    ))
+}
+
+/// Updates the timing table of `year` in the README with `timings`. Days that are already in
+/// the table but were not measured again are kept, as are the tables of other years.
+pub fn update_readme(year: u16, timings: &[Timing]) -> Result<(), String> {
+    let path = readme_path();
+    fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|readme| {
+            let mut tables = parse_timing_tables(&readme[section_range(&readme)?]);
+            let previous = tables.remove(&year).unwrap_or_default();
+            tables.insert(year, merge_timings(&previous, timings));
+            replace_section(&readme, &timing_section(&tables))
+        })
+        .and_then(|readme| fs::write(&path, readme).map_err(|e| e.to_string()))
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing_table() {
+        let timings = [
+            Timing {
+                day: 1,
+                part_one: Some(Duration::from_micros(25)),
+                part_two: Some(Duration::from_micros(45)),
+            },
+            Timing {
+                day: 12,
+                part_one: Some(Duration::from_millis(2)),
+                part_two: None,
+            },
+        ];
+        assert_eq!(
+            timing_table(2022, &timings),
+            "### 2022\n\n\
+             | Day | Part 1 | Part 2 | Total |\n\
+             | :---: | ---: | ---: | ---: |\n\
+             | [Day 1](./src/y2022/day01.rs) | `25.0µs` | `45.0µs` | `70.0µs` |\n\
+             | [Day 12](./src/y2022/day12.rs) | `2.0ms` | - | `2.0ms` |\n\
+             \n\
+             **Total: 2.07ms**"
+        );
+    }
+
+    #[test]
+    fn test_parse_timing_tables() {
+        let timings = vec![
+            Timing {
+                day: 3,
+                part_one: Some(Duration::from_nanos(500)),
+                part_two: Some(Duration::from_millis(1500)),
+            },
+            Timing {
+                day: 12,
+                part_one: Some(Duration::from_micros(2500)),
+                part_two: None,
+            },
+        ];
+        let older = vec![Timing {
+            day: 1,
+            part_one: Some(Duration::from_micros(40)),
+            part_two: None,
+        }];
+        let tables = BTreeMap::from([(2021, older), (2022, timings)]);
+
+        let section = timing_section(&tables);
+        assert!(section.starts_with("## Benchmarks\n\n### 2022\n"));
+        assert!(section.contains("**\n\n### 2021\n"));
+        assert_eq!(parse_timing_tables(&section), tables);
+        assert!(parse_timing_tables("").is_empty());
+    }
+
+    #[test]
+    fn test_merge_timings() {
+        let timing = |day, part_one: Option<u64>, part_two: Option<u64>| Timing {
+            day,
+            part_one: part_one.map(Duration::from_millis),
+            part_two: part_two.map(Duration::from_millis),
+        };
+        let previous = [timing(1, Some(1), Some(2)), timing(5, Some(5), Some(6))];
+        // day 5 with `--part 1`, and a new day.
+        let new = [timing(5, Some(7), None), timing(3, Some(3), None)];
+        assert_eq!(
+            merge_timings(&previous, &new),
+            [
+                timing(1, Some(1), Some(2)),
+                timing(3, Some(3), None),
+                timing(5, Some(7), Some(6))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!("# AoC\n{}\nold\ntable\n{}\n\nrest\n", MARKER, MARKER);
+        assert_eq!(
+            replace_section(&readme, "new").unwrap(),
+            format!("# AoC\n{}\nnew\n{}\n\nrest\n", MARKER, MARKER)
+        );
+
+        assert!(replace_section("# AoC\n", "new").is_err());
+        assert!(replace_section(&format!("# AoC\n{}\n", MARKER), "new").is_err());
+    }
+}
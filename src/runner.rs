@@ -0,0 +1,340 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::options::Options;
+use crate::parse::ParseError;
+use crate::{
+    check_part, print_part_header, solve_part, time_part, Day, Failure, PartResult, Status,
+};
+
+/// Stack size of solver threads, the default of spawned threads is too small for deep recursion.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Label of days whose input could not be read in the summary table.
+pub const NO_INPUT: &str = "no input";
+
+thread_local! {
+    /// Set on solver threads, whose panics are kept instead of printed.
+    static CAPTURE_PANICS: Cell<bool> = const { Cell::new(false) };
+    /// Message and location of the last panic on this solver thread.
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs the parts of `day` selected with `--part` like [`crate::run_day`], but catches panics
+/// and gives up on parts that take longer than the `--timeout`.
+pub fn run_day(year: u16, day: &Day, input: &str) -> Vec<PartResult> {
+    let input: Arc<str> = input.into();
+    day.selected_parts()
+        .map(|(part, solver)| {
+            print_part_header(part);
+            let (day, input) = (day.day, input.clone());
+            let result = run_isolated(year, day, part, move || {
+                solve_part(year, day, part, solver, &input)
+            });
+            result.report();
+            result
+        })
+        .collect()
+}
+
+/// Checks the answers of `days` on `jobs` threads, without printing anything. The results are
+/// returned in the order of `days`.
+///
+/// Unless `--skip-timing` is passed, the parts that finished are timed again one after another
+/// afterwards, so that their timings are not skewed by the other days. This is skipped as well
+/// if a part timed out, as it keeps running in the background and would skew them anyway.
+pub fn run_parallel(year: u16, days: &[(&Day, Arc<str>)], jobs: usize) -> Vec<Vec<PartResult>> {
+    let next = AtomicUsize::new(0);
+    let mut results = vec![Vec::new(); days.len()];
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.min(days.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut checked = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some((day, input)) = days.get(i) else {
+                            break checked;
+                        };
+                        let day_results: Vec<PartResult> = day
+                            .selected_parts()
+                            .map(|(part, solver)| {
+                                let (day, input) = (day.day, input.clone());
+                                run_isolated(year, day, part, move || {
+                                    check_part(year, day, part, solver, &input)
+                                })
+                            })
+                            .collect();
+                        checked.push((i, day_results));
+                    }
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            for (i, day_results) in worker.join().expect("worker thread panicked") {
+                results[i] = day_results;
+            }
+        }
+    });
+
+    let timed_out = results
+        .iter()
+        .flatten()
+        .any(|result| result.status() == Status::TimedOut);
+    if !Options::current().skip_timing && !timed_out {
+        for ((day, input), day_results) in days.iter().zip(&mut results) {
+            for result in day_results.iter_mut().filter(|r| r.failure.is_none()) {
+                let solver = if result.part == 1 {
+                    day.part_one
+                } else {
+                    day.part_two
+                };
+                let (part, input, mut checked) = (result.part, input.clone(), result.clone());
+                *result = run_isolated(year, day.day, part, move || {
+                    time_part(&mut checked, solver, &input);
+                    checked
+                });
+            }
+        }
+    }
+
+    results
+}
+
+/// Runs `run` on a separate thread, which should run a part without printing its result.
+///
+/// A panic is reported as [`Failure::Panicked`] with its message. After the `--timeout`, the
+/// part is reported as [`Failure::TimedOut`]. Threads cannot be killed, so the solver keeps
+/// running in the background until it finishes or the process exits.
+pub fn run_isolated(
+    year: u16,
+    day: u8,
+    part: u8,
+    run: impl FnOnce() -> PartResult + Send + 'static,
+) -> PartResult {
+    run_with_timeout(year, day, part, Options::current().timeout(), run)
+}
+
+fn run_with_timeout(
+    year: u16,
+    day: u8,
+    part: u8,
+    timeout: Option<Duration>,
+    run: impl FnOnce() -> PartResult + Send + 'static,
+) -> PartResult {
+    install_panic_hook();
+
+    let (sender, receiver) = mpsc::channel();
+    let timer = Instant::now();
+    thread::Builder::new()
+        .name(format!("day {:02} part {}", day, part))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            CAPTURE_PANICS.with(|capture| capture.set(true));
+            let result = panic::catch_unwind(AssertUnwindSafe(run)).map_err(|payload| {
+                PANIC_MESSAGE
+                    .with(|message| message.take())
+                    .unwrap_or_else(|| payload_message(payload.as_ref()))
+            });
+            // the runner is gone if the part timed out.
+            let _ = sender.send(result);
+        })
+        .expect("failed to spawn solver thread");
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(RecvTimeoutError::from),
+    };
+
+    let failure = match received {
+        Ok(Ok(result)) => return result,
+        Ok(Err(message)) => Failure::Panicked(message),
+        Err(RecvTimeoutError::Timeout) => Failure::TimedOut(timer.elapsed()),
+        Err(RecvTimeoutError::Disconnected) => {
+            Failure::Panicked("solver thread exited without a result".to_string())
+        }
+    };
+
+    PartResult {
+        year,
+        day,
+        part,
+        answer: None,
+        expected: None,
+        elapsed: timer.elapsed(),
+        bench: None,
+        baseline: None,
+        failure: Some(failure),
+        memory: None,
+    }
+}
+
+/// Installs a panic hook that keeps the message of panics on solver threads for
+/// [`run_isolated`]. Panics on other threads are printed as usual, except for a
+/// [`ParseError`] which is shown without the panic noise. [`ParseError::raise`] installs it
+/// as well, so every entry point renders parse errors the same way.
+pub(crate) fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let parse_error = info.payload().downcast_ref::<ParseError>();
+            if !CAPTURE_PANICS.with(Cell::get) {
+                return match parse_error {
+                    Some(error) => eprintln!("invalid input: {}", error),
+                    None => default_hook(info),
+                };
+            }
+            let mut message = payload_message(info.payload());
+            // the location of a parse error is inside `ParseError::raise`, not in the solution.
+            if let (None, Some(location)) = (parse_error, info.location()) {
+                write!(message, " ({})", location).unwrap();
+            }
+            PANIC_MESSAGE.with(|last| last.replace(Some(message)));
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else if let Some(error) = payload.downcast_ref::<ParseError>() {
+        format!("invalid input: {}", error)
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Formats a table with one line per day and the label of each part, `-` for parts that
+/// were not run.
+pub fn summary_table(rows: &[(u8, [Option<&str>; 2])]) -> String {
+    let width = rows
+        .iter()
+        .flat_map(|(_, parts)| parts.iter().flatten())
+        .map(|label| label.chars().count())
+        .chain(["Part 1".len()])
+        .max()
+        .unwrap_or_default();
+
+    let line = |day: &str, part_one: &str, part_two: &str| {
+        let line = format!("{:4} {:width$}  {}", day, part_one, part_two);
+        line.trim_end().to_string() + "\n"
+    };
+
+    let mut table = line("Day", "Part 1", "Part 2");
+    for (day, [part_one, part_two]) in rows {
+        table += &line(
+            &format!("{:02}", day),
+            part_one.unwrap_or("-"),
+            part_two.unwrap_or("-"),
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part_one(input: &str) -> Option<crate::Answer> {
+        match input {
+            "panic" => panic!("At the disco"),
+            "invalid" => ParseError::new(input, 2, "a number").raise(),
+            "loop" => loop {
+                thread::sleep(Duration::from_millis(10));
+            },
+            _ => Some(input.len().into()),
+        }
+    }
+
+    #[test]
+    fn test_run_isolated() {
+        let timeout = Some(Duration::from_millis(200));
+        let run = |input: &'static str| {
+            run_with_timeout(2022, 25, 1, timeout, move || {
+                crate::check_part(2022, 25, 1, part_one, input)
+            })
+        };
+
+        let solved = run("abc");
+        assert_eq!(solved.status(), Status::Solved);
+        assert_eq!(solved.answer, Some(3.into()));
+
+        let panicked = run("panic");
+        assert_eq!(panicked.status(), Status::Panicked);
+        match panicked.failure {
+            Some(Failure::Panicked(message)) => {
+                assert!(message.starts_with("At the disco (src/runner.rs:"))
+            }
+            failure => panic!("unexpected failure {:?}", failure),
+        }
+
+        let invalid = run("invalid");
+        match invalid.failure {
+            Some(Failure::Panicked(message)) => assert_eq!(
+                message,
+                "invalid input: line 1, column 3: expected a number\n  |\n1 | invalid\n  |   ^"
+            ),
+            failure => panic!("unexpected failure {:?}", failure),
+        }
+
+        let timed_out = run("loop");
+        assert_eq!(timed_out.status(), Status::TimedOut);
+        assert!(timed_out.elapsed >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_run_parallel() {
+        let day = |day| Day {
+            day,
+            part_one,
+            part_two: part_one,
+        };
+        let (first, second) = (day(24), day(25));
+        let days = [(&first, "panic".into()), (&second, "abcd".into())];
+
+        let results = run_parallel(2022, &days, 2);
+        let statuses: Vec<Vec<Status>> = results
+            .iter()
+            .map(|parts| parts.iter().map(PartResult::status).collect())
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                [Status::Panicked, Status::Panicked],
+                [Status::Solved, Status::Solved]
+            ]
+        );
+        assert_eq!(results[1][1].day, 25);
+        assert_eq!(results[1][1].answer, Some(4.into()));
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let rows = [
+            (1, [Some("correct"), Some("timed_out")]),
+            (2, [Some("panicked"), None]),
+        ];
+        assert_eq!(
+            summary_table(&rows),
+            "Day  Part 1     Part 2\n\
+             01   correct    timed_out\n\
+             02   panicked   -\n"
+        );
+    }
+}
@@ -0,0 +1,384 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+use toml::Value;
+
+use crate::client::{self, Client};
+
+/// Server response to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, try again after the given time.
+    Wait(Duration),
+    /// The part is locked or was already solved.
+    WrongLevel,
+}
+
+impl Verdict {
+    fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct => Some("correct"),
+            Verdict::Wrong => Some("wrong"),
+            Verdict::TooHigh => Some("too_high"),
+            Verdict::TooLow => Some("too_low"),
+            Verdict::Wait(_) | Verdict::WrongLevel => None,
+        }
+    }
+
+    fn from_str(verdict: &str) -> Option<Self> {
+        match verdict {
+            "correct" => Some(Verdict::Correct),
+            "wrong" => Some(Verdict::Wrong),
+            "too_high" => Some(Verdict::TooHigh),
+            "too_low" => Some(Verdict::TooLow),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the verdict from the HTML page returned after submitting an answer.
+/// Also returns how long the server asks to wait before the next submission.
+pub fn parse_response(html: &str) -> Option<(Verdict, Option<Duration>)> {
+    let cooldown = Regex::new(r"(?i)please wait (one|\d+) minutes?").unwrap();
+    let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+
+    let cooldown = cooldown.captures(html).map(|captures| {
+        let minutes = match &captures[1] {
+            "one" => 1,
+            minutes => minutes.parse().unwrap(),
+        };
+        Duration::from_secs(minutes * 60)
+    });
+
+    if html.contains("That's the right answer") {
+        Some((Verdict::Correct, None))
+    } else if html.contains("That's not the right answer") {
+        let verdict = if html.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if html.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        Some((verdict, cooldown))
+    } else if let Some(captures) = wait.captures(html) {
+        let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = captures[2].parse().unwrap();
+        let wait = Duration::from_secs(minutes * 60 + seconds);
+        Some((Verdict::Wait(wait), Some(wait)))
+    } else if html.contains("You don't seem to be solving the right level") {
+        Some((Verdict::WrongLevel, None))
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Previously submitted answers of a day, stored in `src/yYYYY/answers/NN.guesses.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Guesses {
+    pub guesses: Vec<Guess>,
+    /// No answer may be submitted before this time, in seconds since the unix epoch.
+    pub wait_until: Option<u64>,
+}
+
+impl Guesses {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let table = match contents.parse::<Value>().map_err(|e| e.to_string())? {
+            Value::Table(table) => table,
+            _ => return Err("expected a table".into()),
+        };
+
+        let wait_until = match table.get("wait_until") {
+            Some(Value::Integer(wait_until)) => Some(*wait_until as u64),
+            _ => None,
+        };
+
+        let guesses = match table.get("guess") {
+            Some(Value::Array(guesses)) => guesses
+                .iter()
+                .map(|guess| {
+                    let part = guess.get("part").and_then(Value::as_integer);
+                    let answer = guess.get("answer").and_then(Value::as_str);
+                    let verdict = guess
+                        .get("verdict")
+                        .and_then(Value::as_str)
+                        .and_then(Verdict::from_str);
+                    match (part, answer, verdict) {
+                        (Some(part), Some(answer), Some(verdict)) => Ok(Guess {
+                            part: part as u8,
+                            answer: answer.to_string(),
+                            verdict,
+                        }),
+                        _ => Err(format!("invalid guess: {}", guess)),
+                    }
+                })
+                .collect::<Result<_, _>>()?,
+            _ => vec![],
+        };
+
+        Ok(Guesses {
+            guesses,
+            wait_until,
+        })
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut table = toml::value::Table::new();
+        if let Some(wait_until) = self.wait_until {
+            table.insert("wait_until".into(), Value::Integer(wait_until as i64));
+        }
+        let guesses = self
+            .guesses
+            .iter()
+            .filter_map(|guess| {
+                let mut entry = toml::value::Table::new();
+                entry.insert("part".into(), Value::Integer(guess.part.into()));
+                entry.insert("answer".into(), Value::String(guess.answer.clone()));
+                entry.insert(
+                    "verdict".into(),
+                    Value::String(guess.verdict.as_str()?.into()),
+                );
+                Some(Value::Table(entry))
+            })
+            .collect();
+        table.insert("guess".into(), Value::Array(guesses));
+        toml::to_string_pretty(&Value::Table(table)).unwrap()
+    }
+
+    /// Checks `answer` against previous guesses. Returns the reason if it should not be submitted,
+    /// i.e. if the part was solved, it was already rejected or it is outside a known bound.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), String> {
+        if let Some(wait_until) = self.wait_until.filter(|wait_until| *wait_until > now) {
+            return Err(format!(
+                "submitted too recently, wait another {}s.",
+                wait_until - now
+            ));
+        }
+
+        let number = answer.parse::<i128>().ok();
+        for guess in self.guesses.iter().filter(|guess| guess.part == part) {
+            let bound = guess.answer.parse::<i128>().ok();
+            match (guess.verdict, number, bound) {
+                (Verdict::Correct, _, _) => {
+                    return Err(format!("already solved with \"{}\".", guess.answer))
+                }
+                (_, _, _) if guess.answer == answer => {
+                    return Err(format!("\"{}\" was already rejected.", answer))
+                }
+                (Verdict::TooHigh, Some(number), Some(bound)) if number >= bound => {
+                    return Err(format!("{} was too high, so {} is too.", bound, number))
+                }
+                (Verdict::TooLow, Some(number), Some(bound)) if number <= bound => {
+                    return Err(format!("{} was too low, so {} is too.", bound, number))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the outcome of a submission.
+    pub fn record(
+        &mut self,
+        part: u8,
+        answer: &str,
+        verdict: Verdict,
+        wait: Option<Duration>,
+        now: u64,
+    ) {
+        if verdict.as_str().is_some() {
+            self.guesses.push(Guess {
+                part,
+                answer: answer.to_string(),
+                verdict,
+            });
+        }
+        self.wait_until = wait.map(|wait| now + wait.as_secs());
+    }
+}
+
+pub fn guesses_path(year: u16, day: u8) -> PathBuf {
+    crate::year_dir(year)
+        .join("answers")
+        .join(format!("{:02}.guesses.toml", day))
+}
+
+/// Reads the guess history of `day`. A missing file means nothing was submitted yet.
+pub fn read_guesses(year: u16, day: u8) -> Result<Guesses, String> {
+    let path = guesses_path(year, day);
+    match fs::read_to_string(&path) {
+        Ok(contents) => Guesses::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Guesses::default()),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+pub fn write_guesses(year: u16, day: u8, guesses: &Guesses) -> Result<(), String> {
+    let path = guesses_path(year, day);
+    fs::write(&path, guesses.to_toml()).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Posts `answer` for the given part and returns the server's verdict.
+pub fn submit(
+    client: &Client,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<(Verdict, Option<Duration>), String> {
+    let html = client
+        .post_form(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+        .map_err(|e: client::Error| e.to_string())?;
+    parse_response(&html).ok_or_else(|| "could not understand the server's response.".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server::serve;
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response("<article><p>That's the right answer! You are one gold star closer"),
+            Some((Verdict::Correct, None))
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too high.  If you're stuck, [...] please wait one minute before trying again."),
+            Some((Verdict::TooHigh, Some(Duration::from_secs(60))))
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too low. [...] please wait 5 minutes before trying again."),
+            Some((Verdict::TooLow, Some(Duration::from_secs(300))))
+        );
+        assert_eq!(
+            parse_response(
+                "<p>That's not the right answer. [...] Please wait one minute before trying again."
+            ),
+            Some((Verdict::Wrong, Some(Duration::from_secs(60))))
+        );
+        assert_eq!(
+            parse_response(
+                "<p>You gave an answer too recently; [...] You have 4m 21s left to wait."
+            ),
+            Some((
+                Verdict::Wait(Duration::from_secs(261)),
+                Some(Duration::from_secs(261))
+            ))
+        );
+        assert_eq!(
+            parse_response("<p>You gave an answer too recently; [...] You have 33s left to wait."),
+            Some((
+                Verdict::Wait(Duration::from_secs(33)),
+                Some(Duration::from_secs(33))
+            ))
+        );
+        assert_eq!(
+            parse_response(
+                "<p>You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some((Verdict::WrongLevel, None))
+        );
+        assert_eq!(parse_response("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_check_guesses() {
+        let mut guesses = Guesses::default();
+        guesses.record(
+            1,
+            "100",
+            Verdict::TooHigh,
+            Some(Duration::from_secs(60)),
+            1000,
+        );
+        guesses.record(1, "10", Verdict::TooLow, None, 1100);
+        guesses.record(1, "CMZ", Verdict::Wrong, None, 1200);
+        guesses.record(2, "7", Verdict::Correct, None, 1300);
+
+        assert!(guesses.check(1, "50", 1300).is_ok());
+        assert!(guesses.check(1, "100", 1300).is_err());
+        assert!(guesses.check(1, "101", 1300).is_err());
+        assert!(guesses.check(1, "10", 1300).is_err());
+        assert!(guesses.check(1, "-3", 1300).is_err());
+        assert!(guesses.check(1, "CMZ", 1300).is_err());
+        assert!(guesses.check(1, "MCD", 1300).is_ok());
+        assert!(guesses.check(2, "8", 1300).is_err());
+
+        guesses.wait_until = Some(1400);
+        assert!(guesses.check(1, "50", 1300).is_err());
+        assert!(guesses.check(1, "50", 1400).is_ok());
+    }
+
+    #[test]
+    fn test_guesses_roundtrip() {
+        let mut guesses = Guesses::default();
+        guesses.record(1, "100", Verdict::TooHigh, None, 0);
+        guesses.record(
+            1,
+            "CMZ",
+            Verdict::Wrong,
+            Some(Duration::from_secs(60)),
+            1000,
+        );
+        guesses.record(
+            1,
+            "50",
+            Verdict::Wait(Duration::from_secs(30)),
+            Some(Duration::from_secs(30)),
+            1010,
+        );
+
+        assert_eq!(guesses.guesses.len(), 2);
+        assert_eq!(guesses.wait_until, Some(1040));
+        assert_eq!(Guesses::parse(&guesses.to_toml()).unwrap(), guesses);
+        assert_eq!(Guesses::parse("").unwrap(), Guesses::default());
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = serve(vec![(
+            200,
+            "<main><article><p>That's not the right answer; your answer is too low. Please wait one minute before trying again.</p></article></main>",
+        )]);
+        let client = Client::new(&base_url, "abc123");
+
+        assert_eq!(
+            submit(&client, 2022, 1, 2, "45000"),
+            Ok((Verdict::TooLow, Some(Duration::from_secs(60))))
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("level=2&answer=45000"));
+    }
+}
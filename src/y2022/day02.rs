@@ -4,7 +4,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     let rounds: Vec<(char, char)> = input
         .trim()
         .split("\n")
-        .map(|row| (row.chars().nth(0).unwrap(), row.chars().nth(2).unwrap()))
+        .map(|row| (row.chars().next().unwrap(), row.chars().nth(2).unwrap()))
         .collect();
 
     let mapping = result_mapping_1();
@@ -20,7 +20,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     let rounds: Vec<(char, char)> = input
         .trim()
         .split("\n")
-        .map(|row| (row.chars().nth(0).unwrap(), row.chars().nth(2).unwrap()))
+        .map(|row| (row.chars().next().unwrap(), row.chars().nth(2).unwrap()))
         .collect();
 
     let mapping = result_mapping_2();
@@ -60,25 +60,19 @@ fn result_mapping_1() -> HashMap<(char, char), u32> {
     mapping
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = crate::read_file(2022, "examples", 2);
         assert_eq!(part_one(&input), Some(15));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = crate::read_file(2022, "examples", 2);
         assert_eq!(part_two(&input), Some(12));
     }
 }
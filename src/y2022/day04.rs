@@ -11,9 +11,8 @@ pub fn part_one(input: &str) -> Option<u32> {
     let mut count = 0;
 
     for pair in range_pairs {
-        if pair[0] <= pair[2] && pair[1] >= pair[3] {
-            count += 1;
-        } else if pair[0] >= pair[2] && pair[1] <= pair[3] {
+        if (pair[0] <= pair[2] && pair[1] >= pair[3]) || (pair[0] >= pair[2] && pair[1] <= pair[3])
+        {
             count += 1;
         }
     }
@@ -33,38 +32,30 @@ pub fn part_two(input: &str) -> Option<u32> {
     let mut count = 0;
 
     for pair in range_pairs {
-        if pair[0] <= pair[2] && pair[1] >= pair[3] {
-            count += 1;
-        } else if pair[0] >= pair[2] && pair[1] <= pair[3] {
-            count += 1;
-        } else if pair[1] >= pair[2] && pair[1] <= pair[3] {
-            count += 1;
-        } else if pair[0] >= pair[2] && pair[0] <= pair[3] {
+        if (pair[0] <= pair[2] && pair[1] >= pair[3])
+            || (pair[0] >= pair[2] && pair[1] <= pair[3])
+            || (pair[1] >= pair[2] && pair[1] <= pair[3])
+            || (pair[0] >= pair[2] && pair[0] <= pair[3])
+        {
             count += 1;
         }
     }
     Some(count)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = crate::read_file(2022, "examples", 4);
         assert_eq!(part_one(&input), Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = crate::read_file(2022, "examples", 4);
         assert_eq!(part_two(&input), Some(4));
     }
 }
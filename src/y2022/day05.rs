@@ -15,12 +15,12 @@ fn parse_stacks(stacks_input: &str) -> Vec<Vec<char>> {
 
     for stack_line in stack_lines.into_iter().rev() {
         // println!("Stack line: {stack_line}");
-        for i in 0..count {
+        for (i, stack) in stacks.iter_mut().enumerate() {
             let letter = stack_line.chars().nth((i * 4) + 1).unwrap();
             // println!("Letter: {letter}");
 
             if letter.is_alphanumeric() {
-                stacks[i].push(letter);
+                stack.push(letter);
             }
         }
     }
@@ -97,25 +97,19 @@ pub fn part_two(input: &str) -> Option<String> {
     Some(tops)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = crate::read_file(2022, "examples", 5);
         assert_eq!(part_one(&input), Some("CMZ".to_owned()));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = crate::read_file(2022, "examples", 5);
         assert_eq!(part_two(&input), Some("MCD".to_owned()));
     }
 }
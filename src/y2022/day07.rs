@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::parse::ParseError;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 enum Fs {
     File { size: usize },
@@ -75,58 +77,48 @@ enum Command {
     Ls { contents: HashMap<String, Fs> },
 }
 
-fn parse_commands(input: &str) -> Vec<Command> {
+fn parse_commands(input: &str) -> Result<Vec<Command>, ParseError> {
     let mut lines = input.lines().peekable();
     let mut commands = Vec::new();
 
-    'outer: loop {
-        if let Some(line) = lines.next() {
-            // println!("Matching line: {line}");
-            match line.split(' ').collect::<Vec<&str>>()[..] {
-                ["$", "cd", dir] => {
-                    commands.push(Command::Cd {
-                        path: dir.to_owned(),
-                    });
-                }
-                ["$", "ls"] => {
-                    let mut ls_info = HashMap::new();
-                    'inner: loop {
-                        if let Some(next) = lines.peek() {
-                            if !next.starts_with('$') {
-                                let (p1, p2) =
-                                    lines.next().and_then(|ls| ls.split_once(' ')).unwrap();
-                                if p1 == "dir" {
-                                    ls_info.insert(
-                                        p2.to_owned(),
-                                        Fs::Directory {
-                                            contents: HashMap::new(),
-                                        },
-                                    );
-                                } else {
-                                    let size = p1.parse::<usize>().unwrap();
-                                    ls_info.insert(p2.to_owned(), Fs::File { size });
-                                }
-                            } else {
-                                break 'inner;
-                            }
-                        } else {
-                            commands.push(Command::Ls { contents: ls_info });
-                            break 'outer; // iterator finished
-                        }
+    while let Some(line) = lines.next() {
+        // println!("Matching line: {line}");
+        match line.split(' ').collect::<Vec<&str>>()[..] {
+            ["$", "cd", dir] => {
+                commands.push(Command::Cd {
+                    path: dir.to_owned(),
+                });
+            }
+            ["$", "ls"] => {
+                let mut ls_info = HashMap::new();
+                while let Some(entry) = lines.next_if(|next| !next.starts_with('$')) {
+                    let (p1, p2) = entry.split_once(' ').ok_or_else(|| {
+                        ParseError::at(input, entry, "`dir <name>` or `<size> <name>`")
+                    })?;
+                    if p1 == "dir" {
+                        ls_info.insert(
+                            p2.to_owned(),
+                            Fs::Directory {
+                                contents: HashMap::new(),
+                            },
+                        );
+                    } else {
+                        let size = p1
+                            .parse::<usize>()
+                            .map_err(|_| ParseError::at(input, p1, "a file size"))?;
+                        ls_info.insert(p2.to_owned(), Fs::File { size });
                     }
-                    commands.push(Command::Ls { contents: ls_info });
                 }
-                _ => panic!("Unexpected things happened"),
+                commands.push(Command::Ls { contents: ls_info });
             }
-        } else {
-            break;
+            _ => return Err(ParseError::at(input, line, "`$ cd <dir>` or `$ ls`")),
         }
     }
-    commands
+    Ok(commands)
 }
 
 fn parse_into_fs(input: &str) -> Fs {
-    let commands = parse_commands(input);
+    let commands = parse_commands(input).unwrap_or_else(ParseError::raise);
 
     // println!("{commands:?}");
 
@@ -196,25 +188,30 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = crate::read_file(2022, "examples", 7);
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = crate::read_file(2022, "examples", 7);
         assert_eq!(part_two(&input), Some(24933642));
     }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = parse_commands("$ cd /\n$ ls\n12x a.txt").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.snippet, "12x a.txt");
+        assert_eq!(error.expected, "a file size");
+
+        let error = parse_commands("$ cd /\n$ rm -rf").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
 }
@@ -0,0 +1,66 @@
+use crate::helpers::grid::{Grid, Pos, NEIGHBOURS_4};
+use crate::parse::{grid, ParseError};
+
+fn parse_grid(input: &str) -> Grid<u32> {
+    grid(input, "a tree height from 0 to 9", |c| c.to_digit(10)).unwrap_or_else(ParseError::raise)
+}
+
+fn is_visible(grid: &Grid<u32>, pos: Pos) -> bool {
+    let height = grid[pos];
+    NEIGHBOURS_4
+        .into_iter()
+        .any(|step| grid.ray(pos, step).all(|(_, tree)| *tree < height))
+}
+
+fn visibility_score(grid: &Grid<u32>, pos: Pos) -> u32 {
+    let height = grid[pos];
+    NEIGHBOURS_4
+        .into_iter()
+        .map(|step| {
+            let mut score = 0;
+            for (_, tree) in grid.ray(pos, step) {
+                score += 1;
+                if *tree >= height {
+                    break;
+                }
+            }
+            score
+        })
+        .product()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = parse_grid(input);
+
+    let count = grid
+        .positions()
+        .filter(|pos| is_visible(&grid, *pos))
+        .count();
+
+    Some(count as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = parse_grid(input);
+
+    grid.positions()
+        .map(|pos| visibility_score(&grid, pos))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 8);
+        assert_eq!(part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 8);
+        assert_eq!(part_two(&input), Some(8));
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use crate::helpers::{Direction, Point2};
+
+fn parse_steps(input: &str) -> Vec<(Direction, u32)> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_once(' ')
+                .map(|(dir, steps)| (dir.parse().unwrap(), steps.parse::<u32>().unwrap()))
+                .unwrap()
+        })
+        .collect()
+}
+
+fn tail_follows_head(head: Point2, tail: Point2) -> Point2 {
+    if head.chebyshev(tail) <= 1 {
+        // Adjacent
+        tail
+    } else {
+        // Step once
+        tail + (head - tail).signum()
+    }
+}
+
+fn simulate_rope(steps: &[(Direction, u32)], knots: usize) -> usize {
+    let mut positions = vec![Point2::ORIGIN; knots];
+
+    let mut tail_visited: HashSet<Point2> = HashSet::new();
+    tail_visited.insert(Point2::ORIGIN);
+
+    for (dir, count) in steps {
+        for _step in 0..*count {
+            positions[0] += *dir;
+            for i in 1..knots {
+                positions[i] = tail_follows_head(positions[i - 1], positions[i]);
+            }
+            tail_visited.insert(positions[knots - 1]);
+        }
+    }
+
+    tail_visited.len()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let steps = parse_steps(input);
+
+    Some(simulate_rope(&steps, 2).try_into().unwrap())
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let steps = parse_steps(input);
+
+    Some(simulate_rope(&steps, 10).try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 9);
+        assert_eq!(part_one(&input), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+        assert_eq!(part_two(input), Some(36));
+    }
+}
@@ -1,33 +1,34 @@
+use crate::helpers::ocr;
+use crate::parse::ParseError;
+
 enum Command {
-    NOOP,
-    ADDX(i32),
+    Noop,
+    Addx(i32),
 }
 
-fn parse_commands(input: &str) -> Vec<Command> {
+fn parse_commands(input: &str) -> Result<Vec<Command>, ParseError> {
     input
         .lines()
-        .map(|line| {
-            if line.starts_with("noop") {
-                Command::NOOP
-            } else if line.starts_with("addx") {
-                let (_, x) = line.split_once(' ').unwrap();
-                Command::ADDX(x.parse().unwrap())
-            } else {
-                panic!("At the disco")
-            }
+        .map(|line| match line.split_once(' ') {
+            None if line == "noop" => Ok(Command::Noop),
+            Some(("addx", x)) => x
+                .parse()
+                .map(Command::Addx)
+                .map_err(|_| ParseError::at(input, x, "a number")),
+            _ => Err(ParseError::at(input, line, "`noop` or `addx <number>`")),
         })
         .collect()
 }
 
-fn run(commands: &Vec<Command>) -> Vec<i32> {
+fn run(commands: &[Command]) -> Vec<i32> {
     let mut x_at_time: Vec<i32> = vec![1];
     let mut register_x = 1;
     for cmd in commands {
         match cmd {
-            Command::NOOP => {
+            Command::Noop => {
                 x_at_time.push(register_x);
             }
-            Command::ADDX(x) => {
+            Command::Addx(x) => {
                 x_at_time.push(register_x);
                 x_at_time.push(register_x);
                 register_x += x;
@@ -37,17 +38,17 @@ fn run(commands: &Vec<Command>) -> Vec<i32> {
     x_at_time
 }
 
-fn signal_strength(x_at_time: &Vec<i32>) -> i32 {
+fn signal_strength(x_at_time: &[i32]) -> i32 {
     let mut signal_strength = 0;
-    for t in 0..x_at_time.len() {
+    for (t, x) in x_at_time.iter().enumerate() {
         if t >= 20 && (t - 20) % 40 == 0 {
-            signal_strength += (t as i32) * x_at_time[t];
+            signal_strength += (t as i32) * x;
         }
     }
     signal_strength
 }
 
-fn draw_crt(x_at_time: &Vec<i32>) -> String {
+fn draw_crt(x_at_time: &[i32]) -> String {
     let mut crt = String::new();
     for cycle in 1..241 {
         let horizontal_pixel = (cycle - 1) % 40;
@@ -69,7 +70,7 @@ fn draw_crt(x_at_time: &Vec<i32>) -> String {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let commands = parse_commands(input);
+    let commands = parse_commands(input).unwrap_or_else(ParseError::raise);
     let x_at_time = run(&commands);
     let signal = signal_strength(&x_at_time);
 
@@ -77,16 +78,11 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<String> {
-    let commands = parse_commands(input);
+    let commands = parse_commands(input).unwrap_or_else(ParseError::raise);
     let x_at_time = run(&commands);
     let crt = draw_crt(&x_at_time);
-    Some(crt)
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    // the example doesn't render letters, keep the picture if there is nothing to read.
+    Some(ocr(&crt).unwrap_or(crt))
 }
 
 #[cfg(test)]
@@ -95,13 +91,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = crate::read_file(2022, "examples", 10);
         assert_eq!(part_one(&input), Some(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = crate::read_file(2022, "examples", 10);
         assert_eq!(
             part_two(&input),
             Some(
@@ -116,4 +112,14 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = parse_commands("noop\naddx 3\naddx x\nnoop").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 6));
+        assert_eq!(error.expected, "a number");
+
+        let error = parse_commands("noop\ndisco").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
 }
@@ -24,8 +24,8 @@ struct Monkey {
 impl Value {
     fn get_value(&self, old_level: &u64) -> u64 {
         match self {
-            Value::Old => old_level.clone(),
-            Value::Const(v) => v.clone(),
+            Value::Old => *old_level,
+            Value::Const(v) => *v,
         }
     }
 }
@@ -137,7 +137,7 @@ fn perform_monkey_business(
                     worry_level_after_operation % product_all_monkey_tests
                 };
                 let test_divisible =
-                    final_worry_level.clone() % monkeys[monkey_index].test_divisible_by == 0;
+                    final_worry_level % monkeys[monkey_index].test_divisible_by == 0;
                 let send_item_to_monkey = if test_divisible {
                     monkeys[monkey_index].true_monkey_index
                 } else {
@@ -156,10 +156,8 @@ fn perform_monkey_business(
 
     monkey_inspected_item.sort();
 
-    let two_most_monkeying = monkey_inspected_item[monkey_inspected_item.len() - 2].clone()
-        * monkey_inspected_item[monkey_inspected_item.len() - 1].clone();
-
-    two_most_monkeying
+    monkey_inspected_item[monkey_inspected_item.len() - 2]
+        * monkey_inspected_item[monkey_inspected_item.len() - 1]
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
@@ -178,25 +176,19 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(monkey_business)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = crate::read_file(2022, "examples", 11);
         assert_eq!(part_one(&input), Some(10605));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = crate::read_file(2022, "examples", 11);
         assert_eq!(part_two(&input), Some(2713310158));
     }
 }
@@ -0,0 +1,85 @@
+use crate::helpers::grid::Pos;
+use crate::helpers::{Grid, GridGraph};
+use crate::parse::{grid, ParseError};
+
+fn parse(input: &str) -> Grid<char> {
+    grid(input, "a height from `a` to `z`, `S` or `E`", |c| {
+        matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+    })
+    .unwrap_or_else(ParseError::raise)
+}
+
+/// The start `S` is at height `a`, the goal `E` at height `z`.
+fn elevation(c: char) -> u8 {
+    match c {
+        'S' => b'a',
+        'E' => b'z',
+        c => c as u8,
+    }
+}
+
+/// Steps may go at most one higher, but down any amount.
+fn can_climb(from: &char, to: &char) -> bool {
+    elevation(*to) <= elevation(*from) + 1
+}
+
+fn steps_to_goal(grid: &Grid<char>, starts: impl IntoIterator<Item = Pos>) -> u32 {
+    let end = grid.find(&'E').unwrap();
+    let path = GridGraph::new(grid, can_climb)
+        .shortest_path(starts, |pos| pos == end)
+        .unwrap();
+
+    (path.len() - 1) as u32
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = parse(input);
+    let start = grid.find(&'S').unwrap();
+
+    Some(steps_to_goal(&grid, [start]))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = parse(input);
+    let lowest = grid.positions().filter(|pos| elevation(grid[*pos]) == b'a');
+
+    Some(steps_to_goal(&grid, lowest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::graph::render_path;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 12);
+        assert_eq!(part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 12);
+        assert_eq!(part_two(&input), Some(29));
+    }
+
+    #[test]
+    fn test_path() {
+        let grid = parse(&crate::read_file(2022, "examples", 12));
+        let path = GridGraph::new(&grid, can_climb)
+            .shortest_path([grid.find(&'S').unwrap()], |pos| grid[pos] == 'E')
+            .unwrap();
+        assert_eq!(
+            render_path(&grid, &path),
+            ">>vv<<<<\nabvvv<<^\nacvv>E^^\nacv>>>^^\nab>>>>>^"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let result = std::panic::catch_unwind(|| parse("Sab\nab#"));
+        let payload = result.unwrap_err();
+        let error = payload.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
@@ -5,14 +5,16 @@ use itertools::Itertools;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::one_of,
-    combinator::{all_consuming, map, map_res, recognize},
-    multi::{many0, many1, separated_list0},
+    character::complete::line_ending,
+    combinator::map,
+    multi::{many1, separated_list0, separated_list1},
     sequence::{preceded, separated_pair, terminated},
     IResult,
 };
 
-#[derive(Debug, PartialEq, Eq, Clone, PartialOrd)]
+use crate::parse::{blocks, parse_all, unsigned, ParseError};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
 enum Packet {
     Int(u32),
     List(Vec<Packet>),
@@ -25,9 +27,7 @@ struct Pair {
 }
 
 fn parse_packet_number(input: &str) -> IResult<&str, Packet> {
-    map_res(recognize(many0(one_of("0123456789"))), |out: &str| {
-        u32::from_str_radix(out, 10).map(|i| Packet::Int(i))
-    })(input)
+    map(unsigned, Packet::Int)(input)
 }
 
 fn parse_packet_list(input: &str) -> IResult<&str, Packet> {
@@ -39,27 +39,33 @@ fn parse_packet_list(input: &str) -> IResult<&str, Packet> {
                 tag("]"),
             ),
         ),
-        |list| Packet::List(list),
+        Packet::List,
     )(input)
 }
 
 fn parse_pair(input: &str) -> IResult<&str, Pair> {
     map(
-        separated_pair(parse_packet_list, tag("\n"), parse_packet_list),
+        separated_pair(parse_packet_list, line_ending, parse_packet_list),
         |(left, right)| Pair { left, right },
     )(input)
 }
 
 fn parse_all_pairs(input: &str) -> Vec<Pair> {
-    all_consuming(separated_list0(tag("\n\n"), parse_pair))(input)
-        .unwrap()
-        .1
+    parse_all(blocks(parse_pair), input).unwrap_or_else(ParseError::raise)
 }
 
 fn parse_all_lines(input: &str) -> Vec<Packet> {
-    all_consuming(separated_list0(many1(tag("\n")), parse_packet_list))(input)
-        .unwrap()
-        .1
+    parse_all(
+        separated_list1(many1(line_ending), parse_packet_list),
+        input,
+    )
+    .unwrap_or_else(ParseError::raise)
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl Ord for Packet {
@@ -72,7 +78,7 @@ impl Ord for Packet {
                     .take_while_ref(|&(si, oi)| si.cmp(oi) == Ordering::Equal)
                     .collect::<Vec<(&Packet, &Packet)>>();
                 if let Some((si, oi)) = zipped.next() {
-                    si.cmp(&oi)
+                    si.cmp(oi)
                 } else {
                     s.len().cmp(&o.len())
                 }
@@ -94,7 +100,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     for (index, pair) in pairs.iter().enumerate() {
         let Pair { left, right } = pair;
 
-        if left.cmp(&right) == Ordering::Less {
+        if left.cmp(right) == Ordering::Less {
             sum += index + 1;
         }
     }
@@ -130,25 +136,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some((index_1 * index_2).try_into().unwrap())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = crate::read_file(2022, "examples", 13);
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = crate::read_file(2022, "examples", 13);
         assert_eq!(part_two(&input), Some(140));
     }
 
@@ -156,7 +156,7 @@ mod tests {
     fn test_parse_list() {
         let input = "[1,1,3,1,1]";
         let expected = Packet::List(
-            vec![1, 1, 3, 1, 1]
+            [1, 1, 3, 1, 1]
                 .iter()
                 .map(|i: &u32| Packet::Int(*i))
                 .collect(),
@@ -182,13 +182,13 @@ mod tests {
         let expected = vec![
             Pair {
                 left: Packet::List(
-                    vec![1, 1, 3, 1, 1]
+                    [1, 1, 3, 1, 1]
                         .iter()
                         .map(|i: &u32| Packet::Int(*i))
                         .collect(),
                 ),
                 right: Packet::List(
-                    vec![1, 1, 5, 1, 1]
+                    [1, 1, 5, 1, 1]
                         .iter()
                         .map(|i: &u32| Packet::Int(*i))
                         .collect(),
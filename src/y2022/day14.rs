@@ -1,13 +1,9 @@
+use crate::profile::phase;
 use itertools::Itertools;
 use ndarray::Array2;
-use nom::{
-    bytes::complete::tag,
-    character::complete::{line_ending, one_of},
-    combinator::{all_consuming, map_res, recognize},
-    multi::{many0, separated_list1},
-    sequence::separated_pair,
-    IResult,
-};
+use nom::{bytes::complete::tag, multi::separated_list1, sequence::separated_pair, IResult};
+
+use crate::parse::{lines, parse_all, unsigned, ParseError};
 
 type Point = (u32, u32);
 
@@ -22,14 +18,8 @@ enum Tile {
 
 type Cave = Array2<Tile>;
 
-fn parse_number(input: &str) -> IResult<&str, u32> {
-    map_res(recognize(many0(one_of("0123456789"))), |out: &str| {
-        u32::from_str_radix(out, 10)
-    })(input)
-}
-
 fn parse_point(input: &str) -> IResult<&str, Point> {
-    separated_pair(parse_number, tag(","), parse_number)(input)
+    separated_pair(unsigned, tag(","), unsigned)(input)
 }
 
 fn parse_rocks(input: &str) -> IResult<&str, Rock> {
@@ -37,12 +27,10 @@ fn parse_rocks(input: &str) -> IResult<&str, Rock> {
 }
 
 fn parse_all_rocks(input: &str) -> Vec<Rock> {
-    all_consuming(separated_list1(line_ending, parse_rocks))(input)
-        .unwrap()
-        .1
+    parse_all(lines(parse_rocks), input).unwrap_or_else(ParseError::raise)
 }
 
-fn build_cave(rocks: &Vec<Rock>, with_floor: bool) -> Cave {
+fn build_cave(rocks: &[Rock], with_floor: bool) -> Cave {
     let (max_right, max_down) = rocks
         .iter()
         .flatten()
@@ -131,44 +119,38 @@ fn simulate(cave: &mut Cave) -> u32 {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let all_rocks: Vec<Rock> = parse_all_rocks(input.trim());
+    let all_rocks: Vec<Rock> = phase("parse", || parse_all_rocks(input.trim()));
 
-    let mut cave = build_cave(&all_rocks, false);
+    let mut cave = phase("build cave", || build_cave(&all_rocks, false));
 
-    let sand = simulate(&mut cave);
+    let sand = phase("simulate", || simulate(&mut cave));
 
     Some(sand)
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let all_rocks: Vec<Rock> = parse_all_rocks(input.trim());
+    let all_rocks: Vec<Rock> = phase("parse", || parse_all_rocks(input.trim()));
 
-    let mut cave = build_cave(&all_rocks, true);
+    let mut cave = phase("build cave", || build_cave(&all_rocks, true));
 
-    let sand = simulate(&mut cave);
+    let sand = phase("simulate", || simulate(&mut cave));
 
     Some(sand)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 14);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 14);
+        let input = crate::read_file(2022, "examples", 14);
         assert_eq!(part_one(&input), Some(24));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 14);
+        let input = crate::read_file(2022, "examples", 14);
         assert_eq!(part_two(&input), Some(93));
     }
 }
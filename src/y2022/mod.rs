@@ -0,0 +1,35 @@
+/*
+ * Solutions of 2022 live in this folder, one module per day.
+ * `cargo scaffold` creates new modules and registers them below.
+ */
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+
+days! {
+    1 => day01,
+    2 => day02,
+    3 => day03,
+    4 => day04,
+    5 => day05,
+    6 => day06,
+    7 => day07,
+    8 => day08,
+    9 => day09,
+    10 => day10,
+    11 => day11,
+    12 => day12,
+    13 => day13,
+    14 => day14,
+}